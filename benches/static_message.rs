@@ -0,0 +1,28 @@
+//! Compares [Problem::from_static] with the allocating [Problem::from_message].
+//!
+//! Run with `cargo bench --bench static_message`. Note that with the `backtrace` feature the
+//! backtrace capture dominates both paths.
+
+use problemo::*;
+
+use std::{hint::*, time::*};
+
+const ITERATIONS: usize = 1_000_000;
+const MESSAGE: &str = "skipping malformed row";
+
+fn main() {
+    // Warm up
+    measure(Problem::from_message);
+    measure(Problem::from_static);
+
+    println!("from_message: {:?}", measure(Problem::from_message));
+    println!("from_static:  {:?}", measure(Problem::from_static));
+}
+
+fn measure(new_problem: fn(&'static str) -> Problem) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let _ = black_box(new_problem(black_box(MESSAGE)));
+    }
+    start.elapsed()
+}
@@ -26,9 +26,9 @@ macro_rules! string_attachment {
             }
         }
 
-        impl ::std::convert::Into<::std::string::String> for $type {
-            fn into(self) -> ::std::string::String {
-                self.0
+        impl ::std::convert::From<$type> for ::std::string::String {
+            fn from(outer: $type) -> Self {
+                outer.0
             }
         }
     };
@@ -74,13 +74,62 @@ macro_rules! attachment {
             }
         }
 
-        impl ::std::convert::Into<$inner_type> for $type {
-            fn into(self) -> $inner_type {
-                self.0
+        impl ::std::convert::From<$type> for $inner_type {
+            fn from(outer: $type) -> Self {
+                outer.0
+            }
+        }
+    };
+}
+
+/// Define a displayable attachment type.
+///
+/// Like [attachment!](crate::attachment!) but also with a [Display](std::fmt::Display) that
+/// forwards to the inner value and a `register()` function that registers it with
+/// [register_attachment](crate::register_attachment), so that it is rendered by the formatters,
+/// e.g. the tree style and [Cause::display](crate::Cause::display).
+///
+/// The first argument is the type name. The second argument is the inner type name, which must
+/// implement [Display](std::fmt::Display). The optional third argument is the name used when
+/// rendering, which defaults to the type name.
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// displayable_attachment!(PortAttachment, u16, "port");
+///
+/// PortAttachment::register();
+///
+/// let problem = "cannot connect".into_message_problem().with(PortAttachment(8080));
+/// assert_eq!(problem.top().unwrap().to_string(), "cannot connect (port: 8080)");
+/// assert_eq!(format!("{:#}", problem), "cannot connect\n  port: 8080");
+/// ```
+#[macro_export]
+macro_rules! displayable_attachment {
+    ( $type:ident, $inner_type:ty $(,)? ) => {
+        $crate::displayable_attachment!($type, $inner_type, stringify!($type));
+    };
+
+    ( $type:ident, $inner_type:ty, $name:expr $(,)? ) => {
+        $crate::attachment!($type, $inner_type);
+
+        impl $type {
+            /// Register for rendering.
+            pub fn register() {
+                $crate::register_attachment::<Self, _>($name, |attachment, formatter| {
+                    ::std::fmt::Display::fmt(attachment, formatter)
+                });
+            }
+        }
+
+        impl ::std::fmt::Display for $type {
+            fn fmt(&self, formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, formatter)
             }
         }
     };
 }
 
 #[allow(unused_imports)]
-pub use {attachment, static_string_attachment, string_attachment};
+pub use {attachment, displayable_attachment, static_string_attachment, string_attachment};
@@ -5,4 +5,11 @@ use std::any::*;
 //
 
 /// Captured attachment.
+#[cfg(not(feature = "arc-attachments"))]
 pub type CapturedAttachment = Box<dyn Any + Send + Sync>;
+
+/// Captured attachment.
+///
+/// Shared, so that it can be cheaply cloned.
+#[cfg(feature = "arc-attachments")]
+pub type CapturedAttachment = std::sync::Arc<dyn Any + Send + Sync>;
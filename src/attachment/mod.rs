@@ -1,6 +1,7 @@
 mod attachment;
 mod attachments;
 mod captured;
+mod registry;
 
 #[allow(unused_imports)]
-pub use {attachment::*, attachments::*, captured::*};
+pub use {attachment::*, attachments::*, captured::*, registry::*};
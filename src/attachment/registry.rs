@@ -0,0 +1,96 @@
+use super::captured::*;
+
+use std::{any::*, collections::*, fmt, sync::*};
+
+static REGISTRY: LazyLock<RwLock<HashMap<TypeId, Arc<RegisteredAttachment>>>> =
+    LazyLock::new(Default::default);
+
+/// Register an attachment type so that it can be displayed generically.
+///
+/// Replaces any previous registration for the type.
+///
+/// Example:
+///
+/// ```
+/// # use problemo::*;
+/// string_attachment!(PathAttachment);
+///
+/// register_attachment::<PathAttachment, _>("path", |path, formatter| {
+///     write!(formatter, "{}", path.0)
+/// });
+/// ```
+pub fn register_attachment<AttachmentT, FormatT>(name: &'static str, format: FormatT)
+where
+    AttachmentT: 'static,
+    FormatT: 'static + Fn(&AttachmentT, &mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync,
+{
+    let registered = RegisteredAttachment {
+        name,
+        format: Box::new(
+            move |attachment, formatter| match attachment.downcast_ref() {
+                Some(attachment) => format(attachment, formatter),
+                None => Ok(()),
+            },
+        ),
+    };
+
+    REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(TypeId::of::<AttachmentT>(), registered.into());
+}
+
+/// The registration for an attachment's type, if it was registered.
+pub fn registered_attachment(attachment: &CapturedAttachment) -> Option<Arc<RegisteredAttachment>> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&(**attachment).type_id())
+        .cloned()
+}
+
+//
+// FormatAttachment
+//
+
+/// Type-erased attachment formatter.
+pub type FormatAttachment =
+    Box<dyn Fn(&dyn Any, &mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync>;
+
+//
+// RegisteredAttachment
+//
+
+/// Registered attachment type.
+pub struct RegisteredAttachment {
+    /// Name.
+    pub name: &'static str,
+
+    /// Format.
+    pub format: FormatAttachment,
+}
+
+impl RegisteredAttachment {
+    /// [Display](fmt::Display) for an attachment of the registered type.
+    pub fn display<'own>(&'own self, attachment: &'own CapturedAttachment) -> impl fmt::Display {
+        RegisteredAttachmentDisplay {
+            registered: self,
+            attachment,
+        }
+    }
+}
+
+//
+// RegisteredAttachmentDisplay
+//
+
+struct RegisteredAttachmentDisplay<'own> {
+    registered: &'own RegisteredAttachment,
+    attachment: &'own CapturedAttachment,
+}
+
+impl fmt::Display for RegisteredAttachmentDisplay<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.registered.format)(self.attachment.as_ref(), formatter)
+    }
+}
@@ -0,0 +1,58 @@
+/// Assert that a [Problem](crate::Problem)'s causation chain matches, in order from top to root.
+///
+/// The first argument is the problem. The second argument is either a list of error types or a
+/// list of [Display](std::fmt::Display) messages. Each cause is compared exactly (that is, we do
+/// *not* recurse into [source](std::error::Error::source)).
+///
+/// On mismatch it panics with the actual chain.
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let problem = MalformedError::new("not a number").into_problem().via(InvalidError::default());
+/// assert_problem_chain!(problem, [InvalidError, MalformedError]);
+/// assert_problem_chain!(problem, ["invalid", "malformed: not a number"]);
+/// ```
+#[macro_export]
+macro_rules! assert_problem_chain {
+    ( $problem:expr, [ $( $message:literal ),* $(,)? ] $(,)? ) => {{
+        let problem: &$crate::Problem = &$problem;
+        let expected: &[&str] = &[ $( $message ),* ];
+        if problem.causes.len() != expected.len()
+            || !::std::iter::zip(problem, expected)
+                .all(|(cause, message)| cause.error.to_string() == *message)
+        {
+            ::std::panic!(
+                "problem chain mismatch\n  expected: {:?}\n    actual: {:?}",
+                expected,
+                problem
+                    .causes
+                    .iter()
+                    .map(|cause| cause.error.to_string())
+                    .collect::<::std::vec::Vec<_>>(),
+            );
+        }
+    }};
+
+    ( $problem:expr, [ $( $type:ty ),* $(,)? ] $(,)? ) => {{
+        let problem: &$crate::Problem = &$problem;
+        let mut causes = problem.causes.iter();
+        if !(true $( && causes.next().is_some_and(|cause| cause.error.is::<$type>()) )*
+            && causes.next().is_none())
+        {
+            ::std::panic!(
+                "problem chain mismatch\n  expected: [{}]\n    actual: {:?}",
+                ::std::stringify!($( $type ),*),
+                problem
+                    .causes
+                    .iter()
+                    .map(|cause| &cause.error)
+                    .collect::<::std::vec::Vec<_>>(),
+            );
+        }
+    }};
+}
+
+#[allow(unused_imports)]
+pub use assert_problem_chain;
@@ -1,9 +1,9 @@
 use super::{
-    super::{attachment::*, error::*, problem::*},
+    super::{attachment::*, error::*, format::*, problem::*},
     r#ref::*,
 };
 
-use std::{any::*, error::*};
+use std::{any::*, error::*, fmt};
 
 //
 // Cause
@@ -14,29 +14,60 @@ pub struct Cause {
     /// Error.
     pub error: CapturedError,
 
+    /// Error type name.
+    ///
+    /// Captured from the concrete error type when available, otherwise that of [CapturedError].
+    pub error_type_name: &'static str,
+
     /// Attachments.
     pub attachments: Vec<CapturedAttachment>,
 }
 
 impl Cause {
     /// Constructor.
+    ///
+    /// Because the error is already captured, its concrete type name is unknown. Prefer
+    /// [From] or [new_with_type_name](Cause::new_with_type_name) when possible.
     pub fn new(error: CapturedError) -> Self {
+        Self::new_with_type_name(error, type_name::<CapturedError>())
+    }
+
+    /// Constructor.
+    pub fn new_with_type_name(error: CapturedError, error_type_name: &'static str) -> Self {
         Self {
             error,
+            error_type_name,
             attachments: Default::default(),
         }
     }
 
+    /// Error type name.
+    pub fn error_type_name(&self) -> &'static str {
+        self.error_type_name
+    }
+
     /// To a [CauseRef].
     pub fn to_ref<'problem>(
         &'problem self,
         problem: &'problem Problem,
         depth: usize,
     ) -> CauseRef<'problem, CapturedError> {
-        CauseRef::new(problem, depth, &self.error, self.attachments.as_ref())
+        CauseRef::new(
+            problem,
+            depth,
+            &self.error,
+            self.error_type_name,
+            self.attachments.as_ref(),
+        )
+    }
+
+    /// Number of attachments.
+    pub fn attachment_count(&self) -> usize {
+        self.attachments.len()
     }
 
     /// Attach.
+    #[cfg(not(feature = "arc-attachments"))]
     pub fn attach<AttachmentT>(&mut self, attachment: AttachmentT)
     where
         AttachmentT: Any + Send + Sync,
@@ -44,6 +75,99 @@ impl Cause {
         self.attachments.push(Box::new(attachment));
     }
 
+    /// Attach.
+    #[cfg(feature = "arc-attachments")]
+    pub fn attach<AttachmentT>(&mut self, attachment: AttachmentT)
+    where
+        AttachmentT: Any + Send + Sync,
+    {
+        self.attachments.push(std::sync::Arc::new(attachment));
+    }
+
+    /// First attachment of a type.
+    #[cfg(not(feature = "arc-attachments"))]
+    pub fn attachment_of_type_mut<AttachmentT>(&mut self) -> Option<&mut AttachmentT>
+    where
+        AttachmentT: 'static,
+    {
+        self.attachments
+            .iter_mut()
+            .find_map(|attachment| attachment.downcast_mut())
+    }
+
+    /// First attachment of a type.
+    ///
+    /// Attachments that are shared with other causes are skipped.
+    #[cfg(feature = "arc-attachments")]
+    pub fn attachment_of_type_mut<AttachmentT>(&mut self) -> Option<&mut AttachmentT>
+    where
+        AttachmentT: 'static,
+    {
+        self.attachments.iter_mut().find_map(|attachment| {
+            std::sync::Arc::get_mut(attachment).and_then(|attachment| attachment.downcast_mut())
+        })
+    }
+
+    /// All attachments of a type.
+    #[cfg(not(feature = "arc-attachments"))]
+    pub fn attachments_of_type_mut<AttachmentT>(&mut self) -> impl Iterator<Item = &mut AttachmentT>
+    where
+        AttachmentT: 'static,
+    {
+        self.attachments
+            .iter_mut()
+            .filter_map(|attachment| attachment.downcast_mut())
+    }
+
+    /// All attachments of a type.
+    ///
+    /// Attachments that are shared with other causes are skipped.
+    #[cfg(feature = "arc-attachments")]
+    pub fn attachments_of_type_mut<AttachmentT>(&mut self) -> impl Iterator<Item = &mut AttachmentT>
+    where
+        AttachmentT: 'static,
+    {
+        self.attachments.iter_mut().filter_map(|attachment| {
+            std::sync::Arc::get_mut(attachment).and_then(|attachment| attachment.downcast_mut())
+        })
+    }
+
+    /// Remove the first attachment of a type and return it.
+    #[cfg(not(feature = "arc-attachments"))]
+    pub fn take_attachment_of_type<AttachmentT>(&mut self) -> Option<AttachmentT>
+    where
+        AttachmentT: 'static,
+    {
+        let index = self
+            .attachments
+            .iter()
+            .position(|attachment| attachment.is::<AttachmentT>())?;
+        self.attachments
+            .remove(index)
+            .downcast()
+            .ok()
+            .map(|attachment| *attachment)
+    }
+
+    /// Remove the first attachment of a type and return it.
+    ///
+    /// Attachments that are shared with other causes are skipped.
+    #[cfg(feature = "arc-attachments")]
+    pub fn take_attachment_of_type<AttachmentT>(&mut self) -> Option<AttachmentT>
+    where
+        AttachmentT: 'static + Send + Sync,
+    {
+        let index = self.attachments.iter_mut().position(|attachment| {
+            std::sync::Arc::get_mut(attachment)
+                .is_some_and(|attachment| attachment.is::<AttachmentT>())
+        })?;
+        self.attachments
+            .remove(index)
+            .downcast()
+            .ok()
+            .and_then(|attachment| std::sync::Arc::try_unwrap(attachment).ok())
+    }
+
     /// Attach if [Some].
     pub fn maybe_attach<AttachmentT>(&mut self, attachment: Option<AttachmentT>)
     where
@@ -54,13 +178,70 @@ impl Cause {
         }
     }
 
+    /// Clone with a different error while sharing our attachments.
+    #[cfg(feature = "arc-attachments")]
+    pub fn clone_with_error(&self, error: CapturedError) -> Self {
+        Self {
+            error,
+            error_type_name: self.error_type_name,
+            attachments: self.attachments.clone(),
+        }
+    }
+
     /// Attach a backtrace if we don't already have one.
+    ///
+    /// The backtrace is unresolved, because symbolization is expensive and most problems are never
+    /// displayed. Format it via [backtrace](Cause::backtrace), which resolves it, or resolve it in
+    /// place with [resolve_backtrace](Cause::resolve_backtrace).
     #[cfg(feature = "backtrace")]
     pub fn attach_backtrace(&mut self) {
         if self.attachment_of_type::<backtrace::Backtrace>().is_none() {
-            self.attach(backtrace::Backtrace::new())
+            self.attach(backtrace::Backtrace::new_unresolved())
+        }
+    }
+
+    /// The backtrace, if we have one, for formatting with resolved symbols.
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> Option<ResolvedBacktrace<'_>> {
+        self.attachment_of_type::<backtrace::Backtrace>()
+            .map(ResolvedBacktrace)
+    }
+
+    /// Resolve the backtrace's symbols if we have one.
+    #[cfg(feature = "backtrace")]
+    pub fn resolve_backtrace(&mut self) {
+        if let Some(backtrace) = self.attachment_of_type_mut::<backtrace::Backtrace>() {
+            backtrace.resolve();
         }
     }
+
+    /// [Display](fmt::Display) for the error followed by its displayable attachments in
+    /// parentheses.
+    ///
+    /// The attachments are the same as those of [RenderStyle::Tree].
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let problem = "disk on fire"
+    ///     .into_message_problem()
+    ///     .with_suggestion("call the fire department")
+    ///     .with_related("smoke alarm".into_message_problem());
+    ///
+    /// let cause = problem.top().unwrap();
+    /// assert_eq!(
+    ///     cause.to_string(),
+    ///     "disk on fire (help: call the fire department, related: smoke alarm)"
+    /// );
+    /// ```
+    pub fn display(&self) -> impl fmt::Display {
+        InlineCause(self)
+    }
+}
+
+impl fmt::Display for Cause {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display(), formatter)
+    }
 }
 
 impl Attachments for Cause {
@@ -74,6 +255,11 @@ where
     ErrorT: 'static + Error + Send + Sync,
 {
     fn from(error: ErrorT) -> Self {
-        Self::new(error.into())
+        debug_assert!(
+            !is_problem_wrapper::<ErrorT>(),
+            "wrapped problem added as a cause: {}",
+            type_name::<ErrorT>()
+        );
+        Self::new_with_type_name(Box::new(error), type_name::<ErrorT>())
     }
 }
@@ -4,7 +4,7 @@ use super::{
     r#ref::*,
 };
 
-use std::error::*;
+use std::{any::*, error::*};
 
 //
 // CausationChain
@@ -47,8 +47,15 @@ where
         ErrorT: 'static + Error,
     {
         self.into_iter().enumerate().filter_map(|(depth, cause)| {
-            downcast_error_or_source(cause.error.as_ref())
-                .map(|error| CauseRef::new(self.owning_problem(), depth, error, &cause.attachments))
+            downcast_error_or_source(cause.error.as_ref()).map(|error| {
+                CauseRef::new(
+                    self.owning_problem(),
+                    depth,
+                    error,
+                    type_name::<ErrorT>(),
+                    &cause.attachments,
+                )
+            })
         })
     }
 
@@ -89,7 +96,15 @@ where
         self.into_iter().enumerate().filter_map(|(depth, cause)| {
             downcast_error_or_source(cause.error.as_ref())
                 .filter(|cause_error| *error == **cause_error)
-                .map(|error| CauseRef::new(self.owning_problem(), depth, error, &cause.attachments))
+                .map(|error| {
+                    CauseRef::new(
+                        self.owning_problem(),
+                        depth,
+                        error,
+                        type_name::<ErrorT>(),
+                        &cause.attachments,
+                    )
+                })
         })
     }
 
@@ -102,6 +117,45 @@ where
     {
         self.causes_for(error).next()
     }
+
+    /// The first cause with an error of a type for which the predicate is true.
+    ///
+    /// Will recurse into [source](Error::source).
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// # use std::io;
+    /// let problem = io::Error::from(io::ErrorKind::NotFound)
+    ///     .into_problem()
+    ///     .via(io::Error::other("cannot read"));
+    ///
+    /// let cause = problem
+    ///     .cause_where(|error: &io::Error| error.kind() == io::ErrorKind::NotFound)
+    ///     .unwrap();
+    /// assert_eq!(cause.depth, 1);
+    /// ```
+    fn cause_where<ErrorT, PredicateT>(
+        &'own self,
+        mut predicate: PredicateT,
+    ) -> Option<CauseRef<'own, ErrorT>>
+    where
+        ErrorT: 'static + Error,
+        PredicateT: FnMut(&ErrorT) -> bool,
+    {
+        self.into_iter().enumerate().find_map(|(depth, cause)| {
+            downcast_error_or_source(cause.error.as_ref())
+                .filter(|cause_error| predicate(cause_error))
+                .map(|error| {
+                    CauseRef::new(
+                        self.owning_problem(),
+                        depth,
+                        error,
+                        type_name::<ErrorT>(),
+                        &cause.attachments,
+                    )
+                })
+        })
+    }
 }
 
 // Utils
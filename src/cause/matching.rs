@@ -0,0 +1,121 @@
+/// Dispatch on the first matching arm for a [Problem](crate::Problem)'s causation chain.
+///
+/// The first argument is the problem, either as an identifier followed by the arms, or as an
+/// expression followed by a comma and the arms. Arms are separated by commas and are checked in
+/// order. They can be:
+///
+/// * `ErrorType => body` matches if the causation chain has the error type (see
+///   [has_type](crate::CausationChain::has_type)).
+/// * `cause: ErrorType => body` also binds the first [CauseRef](crate::CauseRef) of that type
+///   (see [cause_of_type](crate::CausationChain::cause_of_type)).
+/// * `== error => body` matches if the causation chain has an equal error (see
+///   [has](crate::CausationChain::has)).
+/// * `_ => body` always matches. It must be the last arm. Without it the result is `()`.
+///
+/// Like [has_type](crate::CausationChain::has_type), this will also match errors nested in
+/// [source](std::error::Error::source).
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// # use std::io;
+/// let problem = io::Error::other("disk on fire")
+///     .into_problem()
+///     .via(LowLevelError);
+///
+/// let message = match_problem!(problem {
+///     == NotFoundError::new("file") => "no file".to_string(),
+///     error: io::Error => format!("I/O: {}", error.error),
+///     LowLevelError => "low-level".to_string(),
+///     _ => "other".to_string(),
+/// });
+/// assert_eq!(message, "I/O: disk on fire");
+/// ```
+#[macro_export]
+macro_rules! match_problem {
+    ( $problem:ident { $( $arms:tt )* } ) => {
+        $crate::match_problem!(@arms (&$problem); $( $arms )*)
+    };
+
+    ( $problem:expr, { $( $arms:tt )* } $(,)? ) => {
+        $crate::match_problem!(@arms (&$problem); $( $arms )*)
+    };
+
+    ( @arms $problem:tt; ) => {
+        ()
+    };
+
+    ( @arms $problem:tt; _ => $body:expr $(,)? ) => {
+        $body
+    };
+
+    ( @arms $problem:tt; == $error:expr => $body:expr $( , $( $rest:tt )* )? ) => {
+        if $crate::CausationChain::has($problem, &$error) {
+            $body
+        } else {
+            $crate::match_problem!(@arms $problem; $( $( $rest )* )?)
+        }
+    };
+
+    ( @arms $problem:tt; $cause:ident : $type:ty => $body:expr $( , $( $rest:tt )* )? ) => {
+        if let ::std::option::Option::Some($cause) =
+            $crate::CausationChain::cause_of_type::<$type>($problem)
+        {
+            $body
+        } else {
+            $crate::match_problem!(@arms $problem; $( $( $rest )* )?)
+        }
+    };
+
+    ( @arms $problem:tt; $type:ty => $body:expr $( , $( $rest:tt )* )? ) => {
+        if $crate::CausationChain::has_type::<$type>($problem) {
+            $body
+        } else {
+            $crate::match_problem!(@arms $problem; $( $( $rest )* )?)
+        }
+    };
+}
+
+/// True if a [Problem](crate::Problem)'s causation chain has any of the error types.
+///
+/// Expands to a `||` of [has_type](crate::CausationChain::has_type) calls.
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// # use std::io;
+/// tag_error!(TimeoutError, "timeout");
+/// tag_error!(ThrottledError, "throttled");
+///
+/// let mut attempts = 0;
+/// let result = loop {
+///     attempts += 1;
+///     let result = if attempts < 3 {
+///         Err(TimeoutError.into_problem())
+///     } else {
+///         Ok("connected")
+///     };
+///
+///     match result {
+///         Err(problem) if has_any_type!(problem, [TimeoutError, ThrottledError, io::Error]) => {
+///             continue;
+///         }
+///         result => break result,
+///     }
+/// };
+///
+/// assert_eq!(result.unwrap(), "connected");
+/// assert_eq!(attempts, 3);
+/// ```
+#[macro_export]
+macro_rules! has_any_type {
+    ( $problem:expr, [ $( $type:ty ),+ $(,)? ] $(,)? ) => {{
+        let problem = &$problem;
+        false $( || $crate::CausationChain::has_type::<$type>(&*problem) )+
+    }};
+}
+
+#[allow(unused_imports)]
+pub use {has_any_type, match_problem};
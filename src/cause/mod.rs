@@ -1,7 +1,9 @@
+mod assert;
 mod cause;
 mod chain;
 mod iterator;
+mod matching;
 mod r#ref;
 
 #[allow(unused_imports)]
-pub use {cause::*, chain::*, iterator::*, r#ref::*};
+pub use {assert::*, cause::*, chain::*, iterator::*, matching::*, r#ref::*};
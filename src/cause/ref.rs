@@ -3,6 +3,8 @@ use super::{
     iterator::*,
 };
 
+use std::fmt;
+
 //
 // CauseRef
 //
@@ -20,6 +22,9 @@ pub struct CauseRef<'problem, ErrorT> {
     /// This error could be either on the causation chain or nested in [source](Error::source).
     pub error: &'problem ErrorT,
 
+    /// Error type name.
+    pub error_type_name: &'static str,
+
     /// Attachments.
     pub attachments: &'problem Vec<CapturedAttachment>,
 }
@@ -30,12 +35,14 @@ impl<'problem, ErrorT> CauseRef<'problem, ErrorT> {
         problem: &'problem Problem,
         depth: usize,
         error: &'problem ErrorT,
+        error_type_name: &'static str,
         attachments: &'problem Vec<CapturedAttachment>,
     ) -> Self {
         CauseRef {
             problem,
             depth,
             error,
+            error_type_name,
             attachments,
         }
     }
@@ -56,6 +63,16 @@ impl<'problem, ErrorT> CauseRef<'problem, ErrorT> {
         self.iter_under().next()
     }
 
+    /// Error type name.
+    pub fn error_type_name(&self) -> &'static str {
+        self.error_type_name
+    }
+
+    /// Number of attachments.
+    pub fn attachment_count(&self) -> usize {
+        self.attachments.len()
+    }
+
     /// Whether we are the top cause.
     pub fn is_top(&self) -> bool {
         self.depth == 0
@@ -72,3 +89,23 @@ impl<'problem, ErrorT> Attachments for CauseRef<'problem, ErrorT> {
         self.attachments.iter()
     }
 }
+
+/// Like [Problem]'s [Display](fmt::Display), renders only the error.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let problem = "disk on fire".into_message_problem().via(LowLevelError);
+/// let top = problem.top_ref().unwrap();
+/// assert_eq!(top.to_string(), "low-level");
+///
+/// let under: Vec<_> = top.iter_under().map(|cause| cause.to_string()).collect();
+/// assert_eq!(under, ["disk on fire"]);
+/// ```
+impl<'problem, ErrorT> fmt::Display for CauseRef<'problem, ErrorT>
+where
+    ErrorT: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.error, formatter)
+    }
+}
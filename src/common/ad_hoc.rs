@@ -0,0 +1,66 @@
+use super::super::{into::*, problem::*};
+
+use std::{error::Error, fmt};
+
+//
+// AdHocError
+//
+
+/// Ad-hoc error type.
+///
+/// Its [Display](fmt::Display) is computed by a function every time it is formatted. Use it for
+/// one-off errors that don't merit a named type.
+pub struct AdHocError {
+    /// Display function.
+    pub display: Box<dyn Fn(&mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync>,
+}
+
+impl AdHocError {
+    /// Constructor.
+    pub fn new<DisplayT>(display: DisplayT) -> Self
+    where
+        DisplayT: 'static + Fn(&mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync,
+    {
+        Self {
+            display: Box::new(display),
+        }
+    }
+}
+
+impl fmt::Debug for AdHocError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("AdHocError")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+impl fmt::Display for AdHocError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.display)(formatter)
+    }
+}
+
+impl Error for AdHocError {}
+
+//
+// Problem
+//
+
+impl Problem {
+    /// Problem for an [AdHocError].
+    ///
+    /// ```
+    /// # use problemo::*;
+    /// let path = String::from("/etc/app.toml");
+    /// let problem = Problem::from_fn(move |formatter| write!(formatter, "cannot read: {}", path));
+    /// assert_eq!(problem.to_string(), "cannot read: /etc/app.toml");
+    /// ```
+    pub fn from_fn<DisplayT>(display: DisplayT) -> Self
+    where
+        DisplayT: 'static + Fn(&mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync,
+    {
+        AdHocError::new(display).into_problem()
+    }
+}
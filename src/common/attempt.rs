@@ -0,0 +1,61 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+//
+// AttemptCount
+//
+
+/// Attempt count attachment.
+///
+/// The number of attempts made, e.g. by [retry](crate::retry).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AttemptCount(pub u32);
+
+impl From<u32> for AttemptCount {
+    fn from(count: u32) -> Self {
+        Self(count)
+    }
+}
+
+//
+// WithAttempt
+//
+
+/// With attempt.
+pub trait WithAttempt {
+    /// With [AttemptCount].
+    fn with_attempt(self, count: u32) -> Self;
+
+    /// The first [AttemptCount].
+    fn attempt(&self) -> Option<u32>;
+}
+
+impl WithAttempt for Problem {
+    fn with_attempt(self, count: u32) -> Self {
+        self.with(AttemptCount::from(count))
+    }
+
+    fn attempt(&self) -> Option<u32> {
+        self.attachment_of_type::<AttemptCount>()
+            .map(|count| count.0)
+    }
+}
+
+//
+// WithAttemptResult
+//
+
+/// With attempt.
+pub trait WithAttemptResult<OkT> {
+    /// With [AttemptCount].
+    fn with_attempt(self, count: u32) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithAttemptResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_attempt(self, count: u32) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_attempt(count))
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::error::*;
 
 message_error!(MessageError);
+static_message_error!(StaticMessageError);
 
 // General
 
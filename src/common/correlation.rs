@@ -0,0 +1,113 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+use std::fmt;
+
+//
+// CorrelationId
+//
+
+/// Correlation ID attachment.
+///
+/// Identifies a problem across systems, e.g. so that users can report it and support can find it
+/// in the logs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CorrelationId(pub String);
+
+impl CorrelationId {
+    /// Constructor.
+    pub fn new<ToStringT>(id: ToStringT) -> Self
+    where
+        ToStringT: ToString,
+    {
+        Self(id.to_string())
+    }
+
+    /// Generated random (v4) UUID.
+    #[cfg(feature = "uuid")]
+    pub fn generate() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+}
+
+impl fmt::Display for CorrelationId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+//
+// WithCorrelationId
+//
+
+/// With correlation ID.
+pub trait WithCorrelationId {
+    /// With [CorrelationId].
+    fn with_correlation_id<ToStringT>(self, id: ToStringT) -> Self
+    where
+        ToStringT: ToString;
+
+    /// With generated [CorrelationId] if we don't already have one.
+    #[cfg(feature = "uuid")]
+    fn with_generated_id(self) -> Self;
+
+    /// The first [CorrelationId].
+    fn correlation_id(&self) -> Option<&str>;
+}
+
+impl WithCorrelationId for Problem {
+    fn with_correlation_id<ToStringT>(self, id: ToStringT) -> Self
+    where
+        ToStringT: ToString,
+    {
+        self.with(CorrelationId::new(id))
+    }
+
+    #[cfg(feature = "uuid")]
+    fn with_generated_id(self) -> Self {
+        if self.correlation_id().is_none() {
+            self.with(CorrelationId::generate())
+        } else {
+            self
+        }
+    }
+
+    fn correlation_id(&self) -> Option<&str> {
+        self.attachment_of_type::<CorrelationId>()
+            .map(|id| id.0.as_str())
+    }
+}
+
+//
+// WithCorrelationIdResult
+//
+
+/// With correlation ID.
+pub trait WithCorrelationIdResult<OkT> {
+    /// With [CorrelationId].
+    fn with_correlation_id<ToStringT>(self, id: ToStringT) -> Result<OkT, Problem>
+    where
+        ToStringT: ToString;
+
+    /// With generated [CorrelationId] if we don't already have one.
+    #[cfg(feature = "uuid")]
+    fn with_generated_id(self) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithCorrelationIdResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_correlation_id<ToStringT>(self, id: ToStringT) -> Result<OkT, Problem>
+    where
+        ToStringT: ToString,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.with_correlation_id(id))
+    }
+
+    #[cfg(feature = "uuid")]
+    fn with_generated_id(self) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_generated_id())
+    }
+}
@@ -1,4 +1,4 @@
-use super::super::{attachment::*, into::*, problem::*, result::*};
+use super::super::{attachment::*, into::*, problem::*, problems::*, result::*};
 
 use std::{error::Error, fmt, process::*};
 
@@ -20,6 +20,51 @@ impl ExitCodeAttachment {
     }
 }
 
+//
+// ExitCodeError
+//
+
+/// Error that declares its own exit code.
+///
+/// Because this cannot be detected through `dyn Error`, use
+/// [with_exit_code_from](WithExitCode::with_exit_code_from) to add the error together with its
+/// [ExitCodeAttachment].
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// # use std::{fmt, process::*};
+/// #[derive(Debug)]
+/// enum CliError {
+///     Usage,
+///     Config,
+/// }
+///
+/// impl fmt::Display for CliError {
+///     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         fmt::Debug::fmt(self, formatter)
+///     }
+/// }
+///
+/// impl std::error::Error for CliError {}
+///
+/// impl ExitCodeError for CliError {
+///     fn exit_code(&self) -> ExitCode {
+///         match self {
+///             Self::Usage => 64.into(),
+///             Self::Config => 78.into(),
+///         }
+///     }
+/// }
+///
+/// let problem = "missing file".into_message_problem().with_exit_code_from(CliError::Config);
+/// assert!(problem.has_type::<CliError>());
+/// assert_eq!(problem.resolve_exit_code(), ExitCode::from(78));
+/// ```
+pub trait ExitCodeError {
+    /// Exit code.
+    fn exit_code(&self) -> ExitCode;
+}
+
 //
 // WithExitCode
 //
@@ -36,6 +81,17 @@ pub trait WithExitCode {
 
     /// With success [ExitCodeAttachment].
     fn with_success_exit_code(self) -> Self;
+
+    /// Adds the error to the top of the causation chain with the [ExitCodeAttachment] it
+    /// declares.
+    fn with_exit_code_from<ErrorT>(self, error: ErrorT) -> Self
+    where
+        ErrorT: 'static + ExitCodeError + Error + Send + Sync;
+
+    /// The first [ExitCodeAttachment], defaulting to [ExitCode::FAILURE].
+    ///
+    /// This includes an empty problem, which is still a failure.
+    fn resolve_exit_code(&self) -> ExitCode;
 }
 
 impl WithExitCode for Problem {
@@ -53,6 +109,20 @@ impl WithExitCode for Problem {
     fn with_success_exit_code(self) -> Self {
         self.with(ExitCodeAttachment::success())
     }
+
+    fn with_exit_code_from<ErrorT>(self, error: ErrorT) -> Self
+    where
+        ErrorT: 'static + ExitCodeError + Error + Send + Sync,
+    {
+        let exit_code = error.exit_code();
+        self.via(error).with_exit_code(exit_code)
+    }
+
+    fn resolve_exit_code(&self) -> ExitCode {
+        self.attachment_of_type::<ExitCodeAttachment>()
+            .map(|exit_code| exit_code.0)
+            .unwrap_or(ExitCode::FAILURE)
+    }
 }
 
 //
@@ -71,6 +141,12 @@ pub trait WithExitCodeResult<OkT> {
 
     /// With success [ExitCodeAttachment].
     fn with_success_exit_code(self) -> Result<OkT, Problem>;
+
+    /// Adds the error to the top of the causation chain with the [ExitCodeAttachment] it
+    /// declares.
+    fn with_exit_code_from<ErrorT>(self, error: ErrorT) -> Result<OkT, Problem>
+    where
+        ErrorT: 'static + ExitCodeError + Error + Send + Sync;
 }
 
 impl<ResultT, OkT> WithExitCodeResult<OkT> for ResultT
@@ -94,6 +170,62 @@ where
         self.into_problem()
             .map_err(|problem| problem.with_success_exit_code())
     }
+
+    fn with_exit_code_from<ErrorT>(self, error: ErrorT) -> Result<OkT, Problem>
+    where
+        ErrorT: 'static + ExitCodeError + Error + Send + Sync,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.with_exit_code_from(error))
+    }
+}
+
+//
+// Problems
+//
+
+impl Problems {
+    /// Exit code for all the problems.
+    ///
+    /// [ExitCode::SUCCESS] if there are no problems. Otherwise the first [ExitCodeAttachment] of
+    /// the critical problems, if any have one, then the first of all problems, defaulting to
+    /// [ExitCode::FAILURE].
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// # use std::process::*;
+    /// let mut problems = Problems::default();
+    /// assert_eq!(problems.resolve_exit_code(), ExitCode::SUCCESS);
+    ///
+    /// problems.add("bad input".into_message_problem().with_exit_code(65));
+    /// problems.add("disk on fire".into_thread_problem().with_exit_code(74));
+    /// assert_eq!(problems.resolve_exit_code(), ExitCode::from(65));
+    ///
+    /// problems.handle_type_as_critical::<ThreadError>();
+    /// assert_eq!(problems.resolve_exit_code(), ExitCode::from(74));
+    /// ```
+    pub fn resolve_exit_code(&self) -> ExitCode {
+        if self.problems.is_empty() {
+            return ExitCode::SUCCESS;
+        }
+
+        self.iter_critical()
+            .chain(self)
+            .find_map(|problem| problem.attachment_of_type::<ExitCodeAttachment>())
+            .map(|exit_code| exit_code.0)
+            .unwrap_or(ExitCode::FAILURE)
+    }
+}
+
+/// Writes the problems, if there are any, to stderr and exits with
+/// [resolve_exit_code](Problems::resolve_exit_code).
+impl Termination for Problems {
+    fn report(self) -> ExitCode {
+        if !self.problems.is_empty() {
+            eprintln!("{}", self);
+        }
+        self.resolve_exit_code()
+    }
 }
 
 //
@@ -129,6 +261,18 @@ impl ExitError {
             .with_failure_exit_code()
     }
 
+    /// Problem for an error with an [ExitCodeAttachment].
+    ///
+    /// Unlike the other constructors this does not use an [ExitError] but instead keeps the
+    /// error at the top of the causation chain, so it remains inspectable.
+    pub fn from_error<ErrorT, ExitCodeT>(error: ErrorT, exit_code: ExitCodeT) -> Problem
+    where
+        ErrorT: 'static + Error + Send + Sync,
+        ExitCodeT: Into<ExitCode>,
+    {
+        error.into_problem().with_exit_code(exit_code)
+    }
+
     /// Problem for [ExitError] with a success [ExitCodeAttachment].
     pub fn success() -> Problem {
         Self(None).into_problem().with_success_exit_code()
@@ -153,3 +297,50 @@ impl PartialEq for ExitError {
 }
 
 impl Eq for ExitError {}
+
+/// Exit code for a [Problem] according to the first matching error type in its causation chain.
+///
+/// Arms are checked in order with [has_type](crate::CausationChain::has_type), so this will also
+/// match errors nested in [source](std::error::Error::source). The code can be anything that
+/// converts into an [ExitCode], e.g. a [u8]. The optional `_` arm must be last. Without it the
+/// default is [ExitCode::FAILURE].
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// # use std::{io, process::*};
+/// let problem = io::Error::other("disk on fire").into_problem().via(LowLevelError);
+///
+/// let exit_code = exit_code_map!(problem, {
+///     InvalidError => 78,
+///     io::Error => 74,
+///     _ => 1,
+/// });
+/// assert_eq!(exit_code, ExitCode::from(74));
+/// ```
+#[macro_export]
+macro_rules! exit_code_map {
+    ( $problem:expr, { $( $arms:tt )* } $(,)? ) => {
+        $crate::exit_code_map!(@arms (&$problem); $( $arms )*)
+    };
+
+    ( @arms $problem:tt; ) => {
+        ::std::process::ExitCode::FAILURE
+    };
+
+    ( @arms $problem:tt; _ => $exit_code:expr $(,)? ) => {
+        ::std::process::ExitCode::from($exit_code)
+    };
+
+    ( @arms $problem:tt; $type:ty => $exit_code:expr $( , $( $rest:tt )* )? ) => {
+        if $crate::CausationChain::has_type::<$type>($problem) {
+            ::std::process::ExitCode::from($exit_code)
+        } else {
+            $crate::exit_code_map!(@arms $problem; $( $( $rest )* )?)
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub use exit_code_map;
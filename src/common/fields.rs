@@ -0,0 +1,84 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+use std::collections::*;
+
+//
+// FieldsAttachment
+//
+
+attachment!(FieldsAttachment, BTreeMap<String, String>);
+
+//
+// WithFields
+//
+
+/// With fields.
+pub trait WithFields {
+    /// Sets a field in the top cause's [FieldsAttachment].
+    ///
+    /// The attachment will be created if it doesn't exist.
+    fn with_field<KeyT, ValueT>(self, key: KeyT, value: ValueT) -> Self
+    where
+        KeyT: ToString,
+        ValueT: ToString;
+
+    /// The first field value for the key in all [FieldsAttachment]s.
+    fn field(&self, key: &str) -> Option<&str>;
+}
+
+impl WithFields for Problem {
+    fn with_field<KeyT, ValueT>(mut self, key: KeyT, value: ValueT) -> Self
+    where
+        KeyT: ToString,
+        ValueT: ToString,
+    {
+        if let Some(cause) = self.top_mut() {
+            match cause.attachment_of_type_mut::<FieldsAttachment>() {
+                Some(fields) => {
+                    fields.0.insert(key.to_string(), value.to_string());
+                }
+
+                None => cause.attach(FieldsAttachment::new([(
+                    key.to_string(),
+                    value.to_string(),
+                )])),
+            }
+        }
+        self
+    }
+
+    fn field(&self, key: &str) -> Option<&str> {
+        self.attachments_of_type::<FieldsAttachment>()
+            .find_map(|fields| fields.0.get(key))
+            .map(|value| value.as_str())
+    }
+}
+
+//
+// WithFieldsResult
+//
+
+/// With fields.
+pub trait WithFieldsResult<OkT> {
+    /// Sets a field in the top cause's [FieldsAttachment].
+    ///
+    /// The attachment will be created if it doesn't exist.
+    fn with_field<KeyT, ValueT>(self, key: KeyT, value: ValueT) -> Result<OkT, Problem>
+    where
+        KeyT: ToString,
+        ValueT: ToString;
+}
+
+impl<ResultT, OkT> WithFieldsResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_field<KeyT, ValueT>(self, key: KeyT, value: ValueT) -> Result<OkT, Problem>
+    where
+        KeyT: ToString,
+        ValueT: ToString,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.with_field(key, value))
+    }
+}
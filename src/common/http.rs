@@ -0,0 +1,86 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+//
+// HttpStatusAttachment
+//
+
+/// HTTP status attachment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HttpStatusAttachment(pub u16);
+
+impl HttpStatusAttachment {
+    /// Internal server error (500).
+    pub fn internal_server_error() -> Self {
+        Self(500)
+    }
+}
+
+impl Default for HttpStatusAttachment {
+    fn default() -> Self {
+        Self::internal_server_error()
+    }
+}
+
+impl From<u16> for HttpStatusAttachment {
+    fn from(status: u16) -> Self {
+        Self(status)
+    }
+}
+
+//
+// WithHttpStatus
+//
+
+/// With HTTP status.
+pub trait WithHttpStatus {
+    /// With [HttpStatusAttachment].
+    fn with_http_status(self, status: u16) -> Self;
+
+    /// The first [HttpStatusAttachment].
+    fn http_status(&self) -> Option<u16>;
+
+    /// The HTTP status and body.
+    ///
+    /// The status is from the first [HttpStatusAttachment], defaulting to 500. The body is the
+    /// [Display](std::fmt::Display) of the top of the causation chain.
+    fn http_response_parts(&self) -> (u16, String);
+}
+
+impl WithHttpStatus for Problem {
+    fn with_http_status(self, status: u16) -> Self {
+        self.with(HttpStatusAttachment::from(status))
+    }
+
+    fn http_status(&self) -> Option<u16> {
+        self.attachment_of_type::<HttpStatusAttachment>()
+            .map(|status| status.0)
+    }
+
+    fn http_response_parts(&self) -> (u16, String) {
+        (
+            self.http_status()
+                .unwrap_or(HttpStatusAttachment::default().0),
+            self.top_message().unwrap_or_default(),
+        )
+    }
+}
+
+//
+// WithHttpStatusResult
+//
+
+/// With HTTP status.
+pub trait WithHttpStatusResult<OkT> {
+    /// With [HttpStatusAttachment].
+    fn with_http_status(self, status: u16) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithHttpStatusResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_http_status(self, status: u16) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_http_status(status))
+    }
+}
@@ -0,0 +1,84 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+use std::any::*;
+
+//
+// InputAttachment
+//
+
+/// The input that was being processed, e.g. for validation.
+#[derive(Clone, Debug, Default)]
+pub struct InputAttachment<InputT>(pub InputT);
+
+impl<InputT> From<InputT> for InputAttachment<InputT> {
+    fn from(input: InputT) -> Self {
+        Self(input)
+    }
+}
+
+//
+// WithInput
+//
+
+/// With input.
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let problem = InvalidError::new("not a number").into_problem().with_input("12a");
+/// assert_eq!(problem.input_of::<&str>(), Some(&"12a"));
+/// ```
+pub trait WithInput {
+    /// With [InputAttachment].
+    fn with_input<InputT>(self, input: InputT) -> Self
+    where
+        InputT: Any + Send + Sync;
+
+    /// The first [InputAttachment] of a type.
+    fn input_of<InputT>(&self) -> Option<&InputT>
+    where
+        InputT: 'static;
+}
+
+impl WithInput for Problem {
+    fn with_input<InputT>(self, input: InputT) -> Self
+    where
+        InputT: Any + Send + Sync,
+    {
+        self.with(InputAttachment::from(input))
+    }
+
+    fn input_of<InputT>(&self) -> Option<&InputT>
+    where
+        InputT: 'static,
+    {
+        self.attachment_of_type::<InputAttachment<InputT>>()
+            .map(|input| &input.0)
+    }
+}
+
+//
+// WithInputResult
+//
+
+/// With input.
+pub trait WithInputResult<OkT> {
+    /// With [InputAttachment].
+    fn with_input<InputT>(self, input: InputT) -> Result<OkT, Problem>
+    where
+        InputT: Any + Send + Sync;
+}
+
+impl<ResultT, OkT> WithInputResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_input<InputT>(self, input: InputT) -> Result<OkT, Problem>
+    where
+        InputT: Any + Send + Sync,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.with_input(input))
+    }
+}
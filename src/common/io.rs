@@ -0,0 +1,46 @@
+use super::super::{attachment::*, into::*, problem::*};
+
+use std::io;
+
+//
+// OsErrorCodeAttachment
+//
+
+attachment!(OsErrorCodeAttachment, i32);
+
+//
+// IntoIoProblem
+//
+
+/// [io::Error] into a [Problem].
+pub trait IntoIoProblem {
+    /// Into a [Problem].
+    ///
+    /// Attaches an [OsErrorCodeAttachment] if there is a raw OS error code.
+    fn into_io_problem(self) -> Problem;
+}
+
+impl IntoIoProblem for io::Error {
+    fn into_io_problem(self) -> Problem {
+        let os_error_code = self.raw_os_error().map(OsErrorCodeAttachment::from);
+        self.into_problem().maybe_with(os_error_code)
+    }
+}
+
+//
+// IntoIoProblemResult
+//
+
+/// Maps [io::Error] into a [Problem].
+pub trait IntoIoProblemResult<OkT> {
+    /// Maps [io::Error] into a [Problem].
+    ///
+    /// Attaches an [OsErrorCodeAttachment] if there is a raw OS error code.
+    fn into_io_problem(self) -> Result<OkT, Problem>;
+}
+
+impl<OkT> IntoIoProblemResult<OkT> for io::Result<OkT> {
+    fn into_io_problem(self) -> Result<OkT, Problem> {
+        self.map_err(|error| error.into_io_problem())
+    }
+}
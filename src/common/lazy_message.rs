@@ -0,0 +1,53 @@
+use std::{error::Error, fmt, sync::*};
+
+//
+// LazyMessageError
+//
+
+/// Message error that generates its message only when it is first needed.
+///
+/// This avoids the [String] allocation for problems that are never displayed, e.g. when they are
+/// dropped by a filtering receiver. The message is then cached.
+pub struct LazyMessageError {
+    /// Message generator.
+    pub generate: Box<dyn Fn() -> String + Send + Sync>,
+
+    message: OnceLock<String>,
+}
+
+impl LazyMessageError {
+    /// Constructor.
+    pub fn new<GenerateT>(generate: GenerateT) -> Self
+    where
+        GenerateT: 'static + Fn() -> String + Send + Sync,
+    {
+        Self {
+            generate: Box::new(generate),
+            message: Default::default(),
+        }
+    }
+
+    /// The message.
+    ///
+    /// Will generate it if it hasn't yet been generated.
+    pub fn message(&self) -> &str {
+        self.message.get_or_init(|| (self.generate)())
+    }
+}
+
+impl fmt::Debug for LazyMessageError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("LazyMessageError")
+            .field(&self.message())
+            .finish()
+    }
+}
+
+impl fmt::Display for LazyMessageError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.message(), formatter)
+    }
+}
+
+impl Error for LazyMessageError {}
@@ -0,0 +1,123 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+use std::fmt;
+
+//
+// SourceLocation
+//
+
+/// Source location attachment.
+///
+/// See [here!](crate::common::here).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// File.
+    pub file: &'static str,
+
+    /// Line (1-based).
+    pub line: u32,
+
+    /// Column (1-based).
+    pub column: u32,
+}
+
+impl SourceLocation {
+    /// Constructor.
+    pub fn new(file: &'static str, line: u32, column: u32) -> Self {
+        Self { file, line, column }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+//
+// WithSourceLocation
+//
+
+/// With source location.
+pub trait WithSourceLocation {
+    /// With [SourceLocation].
+    fn with_source_location(self, file: &'static str, line: u32, column: u32) -> Self;
+
+    /// The first [SourceLocation].
+    fn source_location(&self) -> Option<&SourceLocation>;
+}
+
+impl WithSourceLocation for Problem {
+    fn with_source_location(self, file: &'static str, line: u32, column: u32) -> Self {
+        self.with(SourceLocation::new(file, line, column))
+    }
+
+    fn source_location(&self) -> Option<&SourceLocation> {
+        self.attachment_of_type()
+    }
+}
+
+//
+// WithSourceLocationResult
+//
+
+/// With source location.
+pub trait WithSourceLocationResult<OkT> {
+    /// With [SourceLocation].
+    fn with_source_location(
+        self,
+        file: &'static str,
+        line: u32,
+        column: u32,
+    ) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithSourceLocationResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_source_location(
+        self,
+        file: &'static str,
+        line: u32,
+        column: u32,
+    ) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_source_location(file, line, column))
+    }
+}
+
+//
+// here!
+//
+
+/// The [SourceLocation] of the macro invocation.
+///
+/// With an argument, calls `with_source_location` on it instead. The argument can be a
+/// [Problem] or a [Result].
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let problem = here!("hello".into_message_problem());
+/// let location = problem.source_location().unwrap();
+/// assert_eq!(location.file, file!());
+/// assert_eq!(location.line, line!() - 3);
+///
+/// let problem = "hello".into_message_problem().with(here!());
+/// assert!(problem.source_location().is_some());
+/// ```
+#[macro_export]
+macro_rules! here {
+    () => {
+        $crate::common::SourceLocation::new(::std::file!(), ::std::line!(), ::std::column!())
+    };
+
+    ( $problem:expr $(,)? ) => {
+        $problem.with_source_location(::std::file!(), ::std::line!(), ::std::column!())
+    };
+}
+
+#[allow(unused_imports)]
+pub use here;
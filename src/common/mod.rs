@@ -1,7 +1,35 @@
+mod ad_hoc;
+mod attempt;
 mod common;
+mod correlation;
 mod exit_code;
+mod fields;
+#[cfg(feature = "http")]
+mod http;
+mod input;
+mod io;
+mod lazy_message;
+mod location;
+mod poison;
 mod problem;
+mod process;
+mod related;
 mod result;
+mod severity;
+mod span;
+mod suggestion;
+mod tag;
+mod thread;
+mod timestamp;
+mod validation;
 
 #[allow(unused_imports)]
-pub use {common::*, exit_code::*, problem::*, result::*};
+pub use {
+    ad_hoc::*, attempt::*, common::*, correlation::*, exit_code::*, fields::*, input::*, io::*,
+    lazy_message::*, location::*, poison::*, problem::*, process::*, related::*, result::*,
+    severity::*, span::*, suggestion::*, tag::*, thread::*, timestamp::*, validation::*,
+};
+
+#[cfg(feature = "http")]
+#[allow(unused_imports)]
+pub use http::*;
@@ -0,0 +1,73 @@
+use super::{
+    super::{into::*, problem::*, receiver::*},
+    common::*,
+};
+
+use std::sync::*;
+
+//
+// RecoverPoisoned
+//
+
+/// Recover the guard of a poisoned lock.
+///
+/// [into_thread_problem](super::IntoCommonProblem::into_thread_problem) discards the guard. These
+/// instead capture the poisoning as a [ThreadError] problem while still handing back the guard,
+/// so that we can continue with the (possibly inconsistent) data.
+pub trait RecoverPoisoned<GuardT> {
+    /// The guard, whether or not the lock is poisoned, and a [ThreadError] problem if it is.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// # use std::{sync::*, thread};
+    /// let mutex = Arc::new(Mutex::new(1));
+    ///
+    /// let mutex_clone = mutex.clone();
+    /// thread::spawn(move || {
+    ///     let _guard = mutex_clone.lock().unwrap();
+    ///     panic!("oops");
+    /// })
+    /// .join()
+    /// .unwrap_err();
+    ///
+    /// let (guard, problem) = mutex.lock().recover_poisoned();
+    /// assert_eq!(*guard, 1);
+    /// assert!(problem.unwrap().has_type::<ThreadError>());
+    /// ```
+    fn recover_poisoned(self) -> (GuardT, Option<Problem>);
+
+    /// The guard, whether or not the lock is poisoned, giving a [ThreadError] problem to a
+    /// [ProblemReceiver] if it is.
+    fn give_recovered<ProblemReceiverT>(
+        self,
+        receiver: &mut ProblemReceiverT,
+    ) -> Result<GuardT, Problem>
+    where
+        ProblemReceiverT: ProblemReceiver;
+}
+
+impl<GuardT> RecoverPoisoned<GuardT> for LockResult<GuardT> {
+    fn recover_poisoned(self) -> (GuardT, Option<Problem>) {
+        match self {
+            Ok(guard) => (guard, None),
+            Err(error) => {
+                let problem = ThreadError::new(&error).into_problem();
+                (error.into_inner(), Some(problem))
+            }
+        }
+    }
+
+    fn give_recovered<ProblemReceiverT>(
+        self,
+        receiver: &mut ProblemReceiverT,
+    ) -> Result<GuardT, Problem>
+    where
+        ProblemReceiverT: ProblemReceiver,
+    {
+        let (guard, problem) = self.recover_poisoned();
+        if let Some(problem) = problem {
+            receiver.give(problem)?;
+        }
+        Ok(guard)
+    }
+}
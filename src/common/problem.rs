@@ -25,3 +25,71 @@ where
         ThreadError::new(self).into()
     }
 }
+
+//
+// Problem
+//
+
+impl Problem {
+    /// Problem for a [MessageError].
+    ///
+    /// Note that `From<&str>` and `From<String>` cannot be implemented for [Problem], because
+    /// they would conflict with its `From` for all errors (the standard library may implement
+    /// [Error](std::error::Error) for them in the future). This is the function to use instead,
+    /// e.g. with [Iterator::map].
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// # use std::io;
+    /// let problems: Problems = ["a", "b"].into_iter().map(Problem::from_message).collect();
+    /// assert_eq!(problems.to_string(), "a\nb");
+    ///
+    /// let problem = Problem::from(io::Error::other("still works"));
+    /// assert!(problem.has_type::<io::Error>());
+    /// ```
+    pub fn from_message<ToStringT>(message: ToStringT) -> Self
+    where
+        ToStringT: ToString,
+    {
+        message.into_message_problem()
+    }
+
+    /// Adds a [MessageError] to the top of the causation chain, but only if the top message is
+    /// missing or blank.
+    ///
+    /// Guarantees a non-empty headline without clobbering a good existing one.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let problem = Problem::from_fn(|_| Ok(())).context_if_empty("cannot save");
+    /// assert_eq!(problem.to_string(), "cannot save: ");
+    ///
+    /// let problem = Problem::from_message("disk on fire").context_if_empty("cannot save");
+    /// assert_eq!(problem.to_string(), "disk on fire");
+    ///
+    /// let problem = Problem::default().context_if_empty("cannot save");
+    /// assert_eq!(problem.to_string(), "cannot save");
+    /// ```
+    pub fn context_if_empty<ToStringT>(self, fallback: ToStringT) -> Self
+    where
+        ToStringT: ToString,
+    {
+        if self
+            .top_message()
+            .is_none_or(|message| message.trim().is_empty())
+        {
+            self.via(MessageError::new(fallback))
+        } else {
+            self
+        }
+    }
+
+    /// Problem for a [StaticMessageError].
+    ///
+    /// Unlike [from_message](Problem::from_message) this does not allocate a [String] for the
+    /// message, which matters when many problems share the same message. Note that the error type
+    /// is not [MessageError].
+    pub fn from_static(message: &'static str) -> Self {
+        StaticMessageError::new(message).into()
+    }
+}
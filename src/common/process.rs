@@ -0,0 +1,91 @@
+use super::super::{problem::*, result::*};
+
+use std::{collections::*, env, process};
+
+//
+// ProcessContext
+//
+
+/// Process context attachment.
+///
+/// Environment variables are not captured by default because they often contain secrets. Use
+/// [with_env_vars](ProcessContext::with_env_vars) to explicitly capture specific ones.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProcessContext {
+    /// Process ID.
+    pub pid: u32,
+
+    /// Command line arguments, including the program.
+    pub args: Vec<String>,
+
+    /// Captured environment variables.
+    pub env: BTreeMap<String, String>,
+}
+
+impl ProcessContext {
+    /// For the current process.
+    ///
+    /// Arguments that are not valid Unicode are converted lossily.
+    pub fn current() -> Self {
+        Self {
+            pid: process::id(),
+            args: env::args_os()
+                .map(|arg| arg.to_string_lossy().into())
+                .collect(),
+            env: Default::default(),
+        }
+    }
+
+    /// With the current values of environment variables.
+    ///
+    /// Variables that are not set (or are not valid Unicode) are skipped.
+    pub fn with_env_vars<IntoIteratorT, NameT>(mut self, names: IntoIteratorT) -> Self
+    where
+        IntoIteratorT: IntoIterator<Item = NameT>,
+        NameT: AsRef<str>,
+    {
+        for name in names {
+            let name = name.as_ref();
+            if let Ok(value) = env::var(name) {
+                self.env.insert(name.into(), value);
+            }
+        }
+        self
+    }
+}
+
+//
+// WithProcessContext
+//
+
+/// With process context.
+pub trait WithProcessContext {
+    /// With [ProcessContext] for the current process.
+    fn with_process_context(self) -> Self;
+}
+
+impl WithProcessContext for Problem {
+    fn with_process_context(self) -> Self {
+        self.with(ProcessContext::current())
+    }
+}
+
+//
+// WithProcessContextResult
+//
+
+/// With process context.
+pub trait WithProcessContextResult<OkT> {
+    /// With [ProcessContext] for the current process.
+    fn with_process_context(self) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithProcessContextResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_process_context(self) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_process_context())
+    }
+}
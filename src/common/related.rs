@@ -0,0 +1,61 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+//
+// RelatedProblem
+//
+
+/// Related problem attachment.
+///
+/// For a failure that is associated with the problem but is not in its causation chain.
+#[derive(Debug)]
+pub struct RelatedProblem(pub Problem);
+
+impl From<Problem> for RelatedProblem {
+    fn from(problem: Problem) -> Self {
+        Self(problem)
+    }
+}
+
+//
+// WithRelated
+//
+
+/// With related.
+pub trait WithRelated {
+    /// With [RelatedProblem].
+    fn with_related(self, problem: Problem) -> Self;
+
+    /// All [RelatedProblem]s.
+    fn related(&self) -> impl Iterator<Item = &Problem>;
+}
+
+impl WithRelated for Problem {
+    fn with_related(self, problem: Problem) -> Self {
+        self.with(RelatedProblem::from(problem))
+    }
+
+    fn related(&self) -> impl Iterator<Item = &Problem> {
+        self.attachments_of_type::<RelatedProblem>()
+            .map(|related| &related.0)
+    }
+}
+
+//
+// WithRelatedResult
+//
+
+/// With related.
+pub trait WithRelatedResult<OkT> {
+    /// With [RelatedProblem].
+    fn with_related(self, related: Problem) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithRelatedResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_related(self, related: Problem) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_related(related))
+    }
+}
@@ -0,0 +1,128 @@
+use super::super::{attachment::*, problem::*, problems::*, result::*};
+
+use std::fmt;
+
+//
+// Severity
+//
+
+/// Severity attachment.
+///
+/// Problems without one should be treated as [Error](Severity::Error).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Note.
+    Note,
+
+    /// Warning.
+    Warning,
+
+    /// Error.
+    #[default]
+    Error,
+}
+
+impl Severity {
+    /// All severities from lowest to highest.
+    pub const ALL: [Severity; 3] = [Self::Note, Self::Warning, Self::Error];
+
+    /// Name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.name(), formatter)
+    }
+}
+
+//
+// WithSeverity
+//
+
+/// With severity.
+pub trait WithSeverity {
+    /// With [Severity].
+    fn with_severity(self, severity: Severity) -> Self;
+
+    /// The first [Severity], defaulting to [Error](Severity::Error).
+    fn severity(&self) -> Severity;
+
+    /// The highest [Severity] in any cause, defaulting to [Error](Severity::Error).
+    fn max_severity(&self) -> Severity;
+}
+
+impl WithSeverity for Problem {
+    fn with_severity(self, severity: Severity) -> Self {
+        self.with(severity)
+    }
+
+    fn severity(&self) -> Severity {
+        self.attachment_of_type().copied().unwrap_or_default()
+    }
+
+    fn max_severity(&self) -> Severity {
+        self.attachments_of_type()
+            .copied()
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+//
+// WithSeverityResult
+//
+
+/// With severity.
+pub trait WithSeverityResult<OkT> {
+    /// With [Severity].
+    fn with_severity(self, severity: Severity) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithSeverityResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_severity(self, severity: Severity) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_severity(severity))
+    }
+}
+
+//
+// Problems
+//
+
+impl Problems {
+    /// Fails if any problem's [max_severity](WithSeverity::max_severity) is at least the
+    /// threshold.
+    ///
+    /// Either way we get all the problems back, so that those below the threshold can still be
+    /// reported.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let mut problems = Problems::default();
+    /// problems.add("unused variable".into_message_problem().with_severity(Severity::Warning));
+    ///
+    /// let problems = problems.check_at_least(Severity::Error).unwrap();
+    /// assert!(problems.check_at_least(Severity::Warning).is_err());
+    /// ```
+    pub fn check_at_least(self, threshold: Severity) -> Result<Self, Self> {
+        if self
+            .problems
+            .iter()
+            .any(|problem| problem.max_severity() >= threshold)
+        {
+            Err(self)
+        } else {
+            Ok(self)
+        }
+    }
+}
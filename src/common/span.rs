@@ -0,0 +1,168 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+use std::{fmt, sync::*};
+
+//
+// SourceSpan
+//
+
+/// Source span attachment.
+///
+/// Points at a byte range in a source text. Its [Display](fmt::Display) renders the line at the
+/// start of the span with a caret underline:
+///
+/// ```text
+/// --> 2:8
+/// 2 | port = "eighty"
+///   |        ^^^^^^^^
+/// ```
+///
+/// Spans that continue past the end of that line are underlined to the end of it. Spans that start
+/// on the line terminator itself, including a CRLF, get a single caret past the end of the line.
+///
+/// Example:
+///
+/// ```
+/// # use problemo::common::*;
+/// let span = SourceSpan::new("ab\r\ncd", 3, 4);
+/// assert_eq!(span.line(), "ab");
+/// assert_eq!(span.to_string(), "--> 1:4\n1 | ab\n  |    ^");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceSpan {
+    /// Source text.
+    pub source: Arc<str>,
+
+    /// Start byte offset (inclusive).
+    pub start: usize,
+
+    /// End byte offset (exclusive).
+    pub end: usize,
+}
+
+impl SourceSpan {
+    /// Constructor.
+    pub fn new<SourceT>(source: SourceT, start: usize, end: usize) -> Self
+    where
+        SourceT: Into<Arc<str>>,
+    {
+        Self {
+            source: source.into(),
+            start,
+            end,
+        }
+    }
+
+    /// Line and column of the start of the span.
+    ///
+    /// Both are 1-based. The column counts characters, not bytes.
+    pub fn line_and_column(&self) -> (usize, usize) {
+        let start = self.clamped_start();
+        let line_start = self.line_start();
+        (
+            self.source[..line_start].matches('\n').count() + 1,
+            self.source[line_start..start].chars().count() + 1,
+        )
+    }
+
+    /// The line at the start of the span, without its line terminator.
+    pub fn line(&self) -> &str {
+        let line_start = self.line_start();
+        let line = &self.source[line_start..];
+        let line = line.split('\n').next().unwrap_or_default();
+        line.strip_suffix('\r').unwrap_or(line)
+    }
+
+    fn clamped_start(&self) -> usize {
+        self.source.floor_char_boundary(self.start)
+    }
+
+    fn line_start(&self) -> usize {
+        let start = self.clamped_start();
+        self.source[..start]
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (line_number, column) = self.line_and_column();
+        let line = self.line();
+
+        let start = (self.clamped_start() - self.line_start()).min(line.len());
+        let end = self
+            .source
+            .floor_char_boundary(self.end)
+            .saturating_sub(self.line_start());
+        let end = end.clamp(start, line.len());
+        let width = line[start..end].chars().count().max(1);
+
+        let gutter = line_number.to_string();
+        writeln!(formatter, "--> {}:{}", line_number, column)?;
+        writeln!(formatter, "{} | {}", gutter, line)?;
+        write!(
+            formatter,
+            "{:gutter$} | {:column$}{}",
+            "",
+            "",
+            "^".repeat(width),
+            gutter = gutter.len(),
+            column = column - 1
+        )
+    }
+}
+
+//
+// WithSpan
+//
+
+/// With span.
+pub trait WithSpan {
+    /// With [SourceSpan].
+    fn with_span<SourceT>(self, source: SourceT, start: usize, end: usize) -> Self
+    where
+        SourceT: Into<Arc<str>>;
+
+    /// The first [SourceSpan].
+    fn span(&self) -> Option<&SourceSpan>;
+}
+
+impl WithSpan for Problem {
+    fn with_span<SourceT>(self, source: SourceT, start: usize, end: usize) -> Self
+    where
+        SourceT: Into<Arc<str>>,
+    {
+        self.with(SourceSpan::new(source, start, end))
+    }
+
+    fn span(&self) -> Option<&SourceSpan> {
+        self.attachment_of_type()
+    }
+}
+
+//
+// WithSpanResult
+//
+
+/// With span.
+pub trait WithSpanResult<OkT> {
+    /// With [SourceSpan].
+    fn with_span<SourceT>(self, source: SourceT, start: usize, end: usize) -> Result<OkT, Problem>
+    where
+        SourceT: Into<Arc<str>>;
+}
+
+impl<ResultT, OkT> WithSpanResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_span<SourceT>(self, source: SourceT, start: usize, end: usize) -> Result<OkT, Problem>
+    where
+        SourceT: Into<Arc<str>>,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.with_span(source, start, end))
+    }
+}
@@ -0,0 +1,53 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+//
+// SuggestionAttachment
+//
+
+string_attachment!(SuggestionAttachment);
+
+//
+// WithSuggestion
+//
+
+/// With suggestion.
+pub trait WithSuggestion {
+    /// With [SuggestionAttachment].
+    fn with_suggestion<ToStringT>(self, suggestion: ToStringT) -> Self
+    where
+        ToStringT: ToString;
+}
+
+impl WithSuggestion for Problem {
+    fn with_suggestion<ToStringT>(self, suggestion: ToStringT) -> Self
+    where
+        ToStringT: ToString,
+    {
+        self.with(SuggestionAttachment::new(suggestion))
+    }
+}
+
+//
+// WithSuggestionResult
+//
+
+/// With suggestion.
+pub trait WithSuggestionResult<OkT> {
+    /// With [SuggestionAttachment].
+    fn with_suggestion<ToStringT>(self, suggestion: ToStringT) -> Result<OkT, Problem>
+    where
+        ToStringT: ToString;
+}
+
+impl<ResultT, OkT> WithSuggestionResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_suggestion<ToStringT>(self, suggestion: ToStringT) -> Result<OkT, Problem>
+    where
+        ToStringT: ToString,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.with_suggestion(suggestion))
+    }
+}
@@ -0,0 +1,85 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+use std::{borrow::*, fmt};
+
+//
+// ErrorTag
+//
+
+/// Error tag attachment.
+///
+/// A stable string identity for an error. Unlike the error's type, it survives serialization, so
+/// it can be used to match problems that crossed a process boundary.
+///
+/// It is usually a static string, but can be owned when restored from serialized data.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ErrorTag(pub Cow<'static, str>);
+
+impl ErrorTag {
+    /// Constructor.
+    pub fn new<TagT>(tag: TagT) -> Self
+    where
+        TagT: Into<Cow<'static, str>>,
+    {
+        Self(tag.into())
+    }
+}
+
+impl fmt::Display for ErrorTag {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+//
+// WithTag
+//
+
+/// With tag.
+pub trait WithTag {
+    /// With [ErrorTag].
+    fn with_tag<TagT>(self, tag: TagT) -> Self
+    where
+        TagT: Into<Cow<'static, str>>;
+
+    /// True if we have the [ErrorTag] in any cause.
+    fn has_tag(&self, tag: &str) -> bool;
+}
+
+impl WithTag for Problem {
+    fn with_tag<TagT>(self, tag: TagT) -> Self
+    where
+        TagT: Into<Cow<'static, str>>,
+    {
+        self.with(ErrorTag::new(tag))
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.attachments_of_type::<ErrorTag>()
+            .any(|error_tag| error_tag.0 == tag)
+    }
+}
+
+//
+// WithTagResult
+//
+
+/// With tag.
+pub trait WithTagResult<OkT> {
+    /// With [ErrorTag].
+    fn with_tag<TagT>(self, tag: TagT) -> Result<OkT, Problem>
+    where
+        TagT: Into<Cow<'static, str>>;
+}
+
+impl<ResultT, OkT> WithTagResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_tag<TagT>(self, tag: TagT) -> Result<OkT, Problem>
+    where
+        TagT: Into<Cow<'static, str>>,
+    {
+        self.into_problem().map_err(|problem| problem.with_tag(tag))
+    }
+}
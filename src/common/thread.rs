@@ -0,0 +1,69 @@
+use super::super::{problem::*, result::*};
+
+use std::thread::{self, Thread, ThreadId};
+
+//
+// ThreadInfoAttachment
+//
+
+/// Thread information attachment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThreadInfoAttachment {
+    /// Thread name.
+    pub name: Option<String>,
+
+    /// Thread ID.
+    pub id: ThreadId,
+}
+
+impl ThreadInfoAttachment {
+    /// For the current thread.
+    pub fn current() -> Self {
+        thread::current().into()
+    }
+}
+
+impl From<Thread> for ThreadInfoAttachment {
+    fn from(thread: Thread) -> Self {
+        Self {
+            name: thread.name().map(|name| name.into()),
+            id: thread.id(),
+        }
+    }
+}
+
+//
+// WithThreadInfo
+//
+
+/// With thread info.
+pub trait WithThreadInfo {
+    /// With [ThreadInfoAttachment] for the current thread.
+    fn with_thread_info(self) -> Self;
+}
+
+impl WithThreadInfo for Problem {
+    fn with_thread_info(self) -> Self {
+        self.with(ThreadInfoAttachment::current())
+    }
+}
+
+//
+// WithThreadInfoResult
+//
+
+/// With thread info.
+pub trait WithThreadInfoResult<OkT> {
+    /// With [ThreadInfoAttachment] for the current thread.
+    fn with_thread_info(self) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithThreadInfoResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_thread_info(self) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_thread_info())
+    }
+}
@@ -0,0 +1,72 @@
+use super::super::{attachment::*, problem::*, result::*};
+
+use std::time::*;
+
+//
+// TimestampAttachment
+//
+
+/// Timestamp attachment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimestampAttachment(pub SystemTime);
+
+impl TimestampAttachment {
+    /// Now.
+    pub fn now() -> Self {
+        SystemTime::now().into()
+    }
+}
+
+impl From<SystemTime> for TimestampAttachment {
+    fn from(timestamp: SystemTime) -> Self {
+        Self(timestamp)
+    }
+}
+
+//
+// WithTimestamp
+//
+
+/// With timestamp.
+pub trait WithTimestamp {
+    /// With [TimestampAttachment] for now if we don't already have one.
+    fn with_timestamp(self) -> Self;
+
+    /// The first [TimestampAttachment].
+    fn timestamp(&self) -> Option<SystemTime>;
+}
+
+impl WithTimestamp for Problem {
+    fn with_timestamp(self) -> Self {
+        if self.timestamp().is_none() {
+            self.with(TimestampAttachment::now())
+        } else {
+            self
+        }
+    }
+
+    fn timestamp(&self) -> Option<SystemTime> {
+        self.attachment_of_type::<TimestampAttachment>()
+            .map(|timestamp| timestamp.0)
+    }
+}
+
+//
+// WithTimestampResult
+//
+
+/// With timestamp.
+pub trait WithTimestampResult<OkT> {
+    /// With [TimestampAttachment] for now if we don't already have one.
+    fn with_timestamp(self) -> Result<OkT, Problem>;
+}
+
+impl<ResultT, OkT> WithTimestampResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_timestamp(self) -> Result<OkT, Problem> {
+        self.into_problem()
+            .map_err(|problem| problem.with_timestamp())
+    }
+}
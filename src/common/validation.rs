@@ -0,0 +1,101 @@
+use super::{
+    super::{attachment::*, into::*, problem::*, problems::*, result::*},
+    common::*,
+};
+
+//
+// FieldName
+//
+
+string_attachment!(FieldName);
+
+//
+// WithFieldName
+//
+
+/// With field name.
+pub trait WithFieldName {
+    /// With [FieldName].
+    fn with_field_name<ToStringT>(self, field_name: ToStringT) -> Self
+    where
+        ToStringT: ToString;
+
+    /// The first [FieldName].
+    fn field_name(&self) -> Option<&str>;
+}
+
+impl WithFieldName for Problem {
+    fn with_field_name<ToStringT>(self, field_name: ToStringT) -> Self
+    where
+        ToStringT: ToString,
+    {
+        self.with(FieldName::new(field_name))
+    }
+
+    fn field_name(&self) -> Option<&str> {
+        self.attachment_of_type::<FieldName>()
+            .map(|field_name| field_name.0.as_str())
+    }
+}
+
+//
+// WithFieldNameResult
+//
+
+/// With field name.
+pub trait WithFieldNameResult<OkT> {
+    /// With [FieldName].
+    fn with_field_name<ToStringT>(self, field_name: ToStringT) -> Result<OkT, Problem>
+    where
+        ToStringT: ToString;
+}
+
+impl<ResultT, OkT> WithFieldNameResult<OkT> for ResultT
+where
+    ResultT: IntoProblemResult<OkT>,
+{
+    fn with_field_name<ToStringT>(self, field_name: ToStringT) -> Result<OkT, Problem>
+    where
+        ToStringT: ToString,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.with_field_name(field_name))
+    }
+}
+
+//
+// Problems
+//
+
+impl Problems {
+    /// Problems from field validation errors.
+    ///
+    /// Each (field name, message) pair becomes an [InvalidError] problem with a [FieldName].
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let problems = Problems::from_field_errors([
+    ///     ("port".into(), "must be positive".into()),
+    ///     ("host".into(), "is required".into()),
+    /// ]);
+    ///
+    /// let fields: Vec<_> = problems
+    ///     .into_iter()
+    ///     .filter_map(|problem| problem.field_name().map(String::from))
+    ///     .collect();
+    /// assert_eq!(fields, ["port", "host"]);
+    /// ```
+    pub fn from_field_errors<IntoIteratorT>(errors: IntoIteratorT) -> Self
+    where
+        IntoIteratorT: IntoIterator<Item = (String, String)>,
+    {
+        errors
+            .into_iter()
+            .map(|(field_name, message)| {
+                InvalidError::from(message)
+                    .into_problem()
+                    .with_field_name(field_name)
+            })
+            .collect()
+    }
+}
@@ -1,4 +1,6 @@
-use super::super::{cause::*, problem::*};
+use super::super::{error::*, problem::*};
+
+use std::{error::*, fmt};
 
 //
 // AnyhowIntoProblem
@@ -10,16 +12,71 @@ pub trait AnyhowIntoProblem {
     fn into_problem(self) -> Problem;
 }
 
+/// Each context layer in the error's [chain](anyhow::Error::chain) becomes a [SourceError]
+/// cause, from top to root. Layers are borrowed from the error, so these causes capture only their
+/// [Display](std::fmt::Display) and not their types.
+///
+/// The root cause is an [AnyhowRootError] that keeps the original error, so the root error's type
+/// can still be found, e.g. by [has_type](crate::CausationChain::has_type).
+///
+/// ```
+/// # use problemo::*;
+/// # use anyhow::Context;
+/// # use std::io;
+/// let error = anyhow::Error::from(io::Error::other("disk on fire"))
+///     .context("write config")
+///     .context("save");
+///
+/// let problem = error.into_problem();
+/// assert_eq!(problem.causes.len(), 3);
+/// assert_eq!(problem.to_string(), "save: write config: disk on fire");
+/// assert_eq!(problem.cause_of_type::<SourceError>().unwrap().depth, 0);
+/// assert!(problem.has_type::<io::Error>());
+/// assert_eq!(problem.cause_of_type::<io::Error>().unwrap().depth, 2);
+/// ```
 impl AnyhowIntoProblem for anyhow::Error {
     fn into_problem(self) -> Problem {
-        let mut problem = Problem::default();
-        problem
-            .causes
-            .push_back(Cause::new(self.into_boxed_dyn_error()));
+        let mut problem = Problem {
+            causes: self
+                .chain()
+                .take(self.chain().len() - 1)
+                .map(|layer| SourceError::new(layer).into())
+                .collect(),
+        };
+        problem.causes.push_back(AnyhowRootError(self).into());
         problem
     }
 }
 
+//
+// AnyhowRootError
+//
+
+/// The root of an [anyhow::Error]'s [chain](anyhow::Error::chain) as a cause.
+///
+/// Its [Display](fmt::Display) and [Debug](fmt::Debug) are those of the
+/// [root cause](anyhow::Error::root_cause), which is also its [source](Error::source). Thus the
+/// root error counts twice in [total_depth](Problem::total_depth).
+pub struct AnyhowRootError(pub anyhow::Error);
+
+impl fmt::Debug for AnyhowRootError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.0.root_cause(), formatter)
+    }
+}
+
+impl fmt::Display for AnyhowRootError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.0.root_cause(), formatter)
+    }
+}
+
+impl Error for AnyhowRootError {
+    fn source(&self) -> Option<&(dyn 'static + Error)> {
+        Some(self.0.root_cause())
+    }
+}
+
 //
 // AnyhowIntoProblemResult
 //
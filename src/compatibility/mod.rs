@@ -1,5 +1,7 @@
 #[cfg(feature = "anyhow")]
 mod anyhow;
+#[cfg(feature = "sentry")]
+mod sentry;
 #[cfg(feature = "serde")]
 mod serde;
 
@@ -7,6 +9,10 @@ mod serde;
 #[allow(unused_imports)]
 pub use anyhow::*;
 
+#[cfg(feature = "sentry")]
+#[allow(unused_imports)]
+pub use sentry::*;
+
 #[cfg(feature = "serde")]
 #[allow(unused_imports)]
 pub use serde::*;
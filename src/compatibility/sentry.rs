@@ -0,0 +1,137 @@
+use super::super::{attachment::*, cause::*, common::*, problem::*};
+
+use sentry::protocol::*;
+
+/// Report a [Problem] to [Sentry](sentry).
+///
+/// See [sentry_event] for how the problem is mapped.
+///
+/// Returns the event ID, which is nil if there is no bound client or the event was dropped.
+pub fn capture_problem(problem: &Problem) -> Uuid {
+    sentry::capture_event(sentry_event(problem))
+}
+
+/// [Sentry](sentry) event for a [Problem].
+///
+/// Each cause becomes an exception in the event's exception chain. Sentry expects the chain in
+/// reverse causation order, so the root cause comes first and the top cause comes last. The
+/// exception's type is the cause's captured error type name and its value is the error's
+/// [Display](std::fmt::Display).
+///
+/// With the `backtrace` feature, a backtrace attachment becomes the exception's stacktrace.
+///
+/// [SuggestionAttachment] and attachments registered with [register_attachment] become event
+/// extras under their names ("help" for suggestions). If several causes have an attachment with
+/// the same name then the one nearest the top wins. The problem's
+/// [severity](WithSeverity::severity) becomes the event's level.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// # use std::io;
+/// let problem = io::Error::other("disk on fire")
+///     .into_problem()
+///     .via(LowLevelError)
+///     .with_suggestion("call the fire department");
+///
+/// let event = sentry_event(&problem);
+/// let exceptions = &event.exception.values;
+/// assert_eq!(exceptions.len(), 2);
+/// assert_eq!(exceptions[0].value.as_deref(), Some("disk on fire"));
+/// assert_eq!(exceptions[1].value.as_deref(), Some("low-level"));
+/// assert_eq!(event.extra["help"], "call the fire department");
+/// ```
+pub fn sentry_event(problem: &Problem) -> Event<'static> {
+    let mut extra = Map::new();
+    for cause in problem {
+        for attachment in &cause.attachments {
+            if let Some(suggestion) = attachment.downcast_ref::<SuggestionAttachment>() {
+                extra
+                    .entry("help".into())
+                    .or_insert_with(|| suggestion.0.clone().into());
+            } else if let Some(registered) = registered_attachment(attachment) {
+                extra
+                    .entry(registered.name.into())
+                    .or_insert_with(|| registered.display(attachment).to_string().into());
+            }
+        }
+    }
+
+    Event {
+        level: level(problem.severity()),
+        exception: problem
+            .into_iter()
+            .rev()
+            .map(exception)
+            .collect::<Vec<_>>()
+            .into(),
+        extra,
+        ..Default::default()
+    }
+}
+
+// Utils
+
+fn level(severity: Severity) -> Level {
+    match severity {
+        Severity::Note => Level::Info,
+        Severity::Warning => Level::Warning,
+        Severity::Error => Level::Error,
+    }
+}
+
+fn exception(cause: &Cause) -> Exception {
+    Exception {
+        ty: cause.error_type_name.into(),
+        value: Some(cause.error.to_string()),
+        stacktrace: stacktrace(cause),
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "backtrace")]
+fn stacktrace(cause: &Cause) -> Option<Stacktrace> {
+    let mut backtrace = cause.attachment_of_type::<backtrace::Backtrace>()?.clone();
+    backtrace.resolve();
+
+    // Sentry expects the outermost frame first
+    let frames = backtrace
+        .frames()
+        .iter()
+        .rev()
+        .flat_map(|frame| {
+            let instruction_addr = Addr::from(frame.ip());
+            let symbols = frame.symbols();
+            if symbols.is_empty() {
+                vec![Frame {
+                    instruction_addr: Some(instruction_addr),
+                    ..Default::default()
+                }]
+            } else {
+                symbols
+                    .iter()
+                    .rev()
+                    .map(|symbol| Frame {
+                        function: symbol.name().map(|name| name.to_string()),
+                        filename: symbol
+                            .filename()
+                            .map(|filename| filename.display().to_string()),
+                        lineno: symbol.lineno().map(u64::from),
+                        colno: symbol.colno().map(u64::from),
+                        instruction_addr: Some(instruction_addr),
+                        ..Default::default()
+                    })
+                    .collect()
+            }
+        })
+        .collect();
+
+    Some(Stacktrace {
+        frames,
+        ..Default::default()
+    })
+}
+
+#[cfg(not(feature = "backtrace"))]
+fn stacktrace(_cause: &Cause) -> Option<Stacktrace> {
+    None
+}
@@ -0,0 +1,95 @@
+use super::{
+    super::super::{attachment::*, cause::*, common::*, problem::*},
+    serialized::*,
+};
+
+use serde::*;
+
+//
+// JsonAttachment
+//
+
+attachment!(JsonAttachment, serde_json::Value);
+
+//
+// ErrorTypeNameAttachment
+//
+
+string_attachment!(ErrorTypeNameAttachment);
+
+//
+// Problem
+//
+
+/// Reconstructs a lossy [Problem].
+///
+/// The expected representation, which is what [Problem]'s [Serialize] writes, is:
+///
+/// ```json
+/// {
+///   "causes": [
+///     { "error": "display", "type": "type name", "tag": "tag", "attachments": [ ... ] }
+///   ]
+/// }
+/// ```
+///
+/// Only "error" is required. Concrete error and attachment types cannot be revived, so:
+///
+/// * Each error becomes a [MessageError] with the serialized [Display](std::fmt::Display). Its
+///   [error type name](Cause::error_type_name) is that of [MessageError].
+/// * The serialized error type name, if present, becomes an [ErrorTypeNameAttachment], which
+///   can be used for coarse matching.
+/// * The tag, if present, is restored as an [ErrorTag], which is the portable way to match.
+/// * Each attachment becomes a [JsonAttachment] with the serialized value.
+///
+/// The result is thus good for display and for matching on tags, e.g. with
+/// [has_tag](WithTag::has_tag), but not for matching on error types, e.g. with
+/// [has_type](crate::CausationChain::has_type).
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let problem: Problem = serde_json::from_str(r#"{
+///   "causes": [
+///     { "error": "cannot load config", "type": "app::LoadError", "tag": "load" },
+///     { "error": "disk on fire", "attachments": [{ "path": "/etc/app.toml" }] }
+///   ]
+/// }"#).unwrap();
+///
+/// assert_eq!(problem.to_string(), "cannot load config: disk on fire");
+/// assert!(problem.has_type::<MessageError>());
+/// assert!(problem.has_tag("load"));
+///
+/// let type_name = problem.attachment_of_type::<ErrorTypeNameAttachment>().unwrap();
+/// assert_eq!(type_name.0, "app::LoadError");
+///
+/// let root = problem.root().unwrap();
+/// let attachment = root.attachment_of_type::<JsonAttachment>().unwrap();
+/// assert_eq!(attachment.0["path"], "/etc/app.toml");
+/// ```
+impl<'de> Deserialize<'de> for Problem {
+    fn deserialize<DeserializerT>(deserializer: DeserializerT) -> Result<Self, DeserializerT::Error>
+    where
+        DeserializerT: Deserializer<'de>,
+    {
+        let problem = SerializedProblem::deserialize(deserializer)?;
+        Ok(Self {
+            causes: problem
+                .causes
+                .into_iter()
+                .map(|serialized| {
+                    let mut cause = Cause::from(MessageError::new(serialized.error));
+                    if let Some(type_name) = serialized.r#type {
+                        cause.attach(ErrorTypeNameAttachment(type_name));
+                    }
+                    if let Some(tag) = serialized.tag {
+                        cause.attach(ErrorTag::new(tag));
+                    }
+                    for attachment in serialized.attachments {
+                        cause.attach(JsonAttachment(attachment));
+                    }
+                    cause
+                })
+                .collect(),
+        })
+    }
+}
@@ -1,5 +1,8 @@
+mod deserialize;
 mod problem;
 mod result;
+mod serialize;
+mod serialized;
 
 #[allow(unused_imports)]
-pub use {problem::*, result::*};
+pub use {deserialize::*, problem::*, result::*, serialize::*};
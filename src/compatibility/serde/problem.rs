@@ -12,7 +12,15 @@ use {
 /// [Problem] with support for [ser::Error] and [de::Error].
 ///
 /// Note that unlike [Problem] it also supports [Error], so take care to avoid adding it to a
-/// [Problem]'s causation chain.
+/// [Problem]'s causation chain. Converting it into a [Problem] unwraps the inner problem:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let error: SerdeProblem = "bad field".into_message_problem().into();
+/// let problem: Problem = error.into();
+/// assert_eq!(problem.causes.len(), 1);
+/// assert!(problem.has_type::<MessageError>());
+/// ```
 pub struct SerdeProblem {
     /// Problem.
     pub problem: Problem,
@@ -0,0 +1,55 @@
+use super::{super::super::problem::*, serialized::*};
+
+use serde::*;
+
+//
+// Problem
+//
+
+/// Writes the representation read by [Problem]'s [Deserialize].
+///
+/// For each cause:
+///
+/// * "error" is its [Display](std::fmt::Display).
+/// * "type" is its [error type name](crate::Cause::error_type_name), or the
+///   [ErrorTypeNameAttachment](crate::ErrorTypeNameAttachment) of a deserialized cause.
+/// * "tag" is its first [ErrorTag](crate::common::ErrorTag), if it has one.
+/// * "attachments" has each [JsonAttachment](crate::JsonAttachment) as is and each
+///   [registered](crate::register_attachment) attachment as an object mapping its name to its
+///   [Display](std::fmt::Display). Other attachments are skipped.
+///
+/// Serializing, deserializing, and serializing again thus produces the same representation.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// displayable_attachment!(PathAttachment, String, "path");
+///
+/// PathAttachment::register();
+///
+/// let problem = "disk on fire"
+///     .into_message_problem()
+///     .with(PathAttachment::new("/etc/app.toml"))
+///     .via(MessageError::new("cannot load config"))
+///     .with_tag("load");
+///
+/// let json = serde_json::to_value(&problem).unwrap();
+/// assert_eq!(json["causes"][0]["error"], "cannot load config");
+/// assert_eq!(json["causes"][0]["tag"], "load");
+/// assert_eq!(json["causes"][1]["attachments"][0]["path"], "/etc/app.toml");
+///
+/// let restored: Problem = serde_json::from_value(json.clone()).unwrap();
+/// assert_eq!(restored.to_string(), "cannot load config: disk on fire");
+/// assert!(restored.has_tag("load"));
+/// assert_eq!(serde_json::to_value(&restored).unwrap(), json);
+/// ```
+impl Serialize for Problem {
+    fn serialize<SerializerT>(
+        &self,
+        serializer: SerializerT,
+    ) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        SerializedProblem::from(self).serialize(serializer)
+    }
+}
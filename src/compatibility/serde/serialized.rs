@@ -0,0 +1,76 @@
+use super::{
+    super::super::{attachment::*, cause::*, common::*, problem::*},
+    deserialize::*,
+};
+
+use serde::*;
+
+//
+// SerializedProblem
+//
+
+/// Wire representation of a [Problem].
+#[derive(Deserialize, Serialize)]
+pub(super) struct SerializedProblem {
+    #[serde(default)]
+    pub causes: Vec<SerializedCause>,
+}
+
+impl From<&Problem> for SerializedProblem {
+    fn from(problem: &Problem) -> Self {
+        Self {
+            causes: problem.into_iter().map(SerializedCause::from).collect(),
+        }
+    }
+}
+
+//
+// SerializedCause
+//
+
+/// Wire representation of a [Cause].
+#[derive(Deserialize, Serialize)]
+pub(super) struct SerializedCause {
+    pub error: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<serde_json::Value>,
+}
+
+impl From<&Cause> for SerializedCause {
+    fn from(cause: &Cause) -> Self {
+        let r#type = match cause.attachment_of_type::<ErrorTypeNameAttachment>() {
+            Some(type_name) => type_name.0.clone(),
+            None => cause.error_type_name.into(),
+        };
+
+        let attachments = cause
+            .attachments
+            .iter()
+            .filter_map(|attachment| {
+                if let Some(json) = attachment.downcast_ref::<JsonAttachment>() {
+                    Some(json.0.clone())
+                } else {
+                    registered_attachment(attachment).map(|registered| {
+                        serde_json::json!({ registered.name: registered.display(attachment).to_string() })
+                    })
+                }
+            })
+            .collect();
+
+        Self {
+            error: cause.error.to_string(),
+            r#type: Some(r#type),
+            tag: cause
+                .attachment_of_type::<ErrorTag>()
+                .map(|tag| tag.0.clone().into_owned()),
+            attachments,
+        }
+    }
+}
@@ -96,7 +96,7 @@ macro_rules! static_message_error {
             where
                 IntoStringT: ::std::convert::Into<&'static str>,
             {
-                message.into::<&'static str>().into()
+                ::std::convert::Into::<&'static str>::into(message).into()
             }
         }
 
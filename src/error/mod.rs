@@ -1,7 +1,8 @@
 mod captured;
 mod message;
 mod problem;
+mod source;
 mod tag;
 
 #[allow(unused_imports)]
-pub use {captured::*, message::*, problem::*, tag::*};
+pub use {captured::*, message::*, problem::*, source::*, tag::*};
@@ -1,6 +1,9 @@
+#[cfg(feature = "serde")]
+use super::super::compatibility::*;
+
 use super::super::problem::*;
 
-use std::{error::*, fmt};
+use std::{any::*, error::*, fmt};
 
 //
 // ProblemAsError
@@ -8,7 +11,19 @@ use std::{error::*, fmt};
 
 /// Straightforward wrapper for [Problem] that adds an implementation of [Error].
 ///
-/// Take care to avoid adding it into a [Problem]'s causation chain.
+/// Take care to avoid adding it into a [Problem]'s causation chain. Converting it into a [Problem]
+/// or giving it to [via](Problem::via) unwraps the inner problem instead. Other ways of adding it,
+/// e.g. [Cause::from](super::super::Cause), panic in debug builds.
+///
+/// Conversion is a round trip in both directions:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let error: ProblemAsError = "disk on fire".into_message_problem().into();
+/// let problem: Problem = error.into();
+/// assert_eq!(problem.causes.len(), 1);
+/// assert!(problem.has_type::<MessageError>());
+/// ```
 pub struct ProblemAsError {
     /// Problem.
     pub problem: Problem,
@@ -33,3 +48,45 @@ impl From<Problem> for ProblemAsError {
         Self { problem }
     }
 }
+
+// Utils
+
+/// True if the error type is [ProblemAsError] or
+/// [SerdeProblem](super::super::compatibility::SerdeProblem).
+pub(crate) fn is_problem_wrapper<ErrorT>() -> bool
+where
+    ErrorT: 'static,
+{
+    let type_id = TypeId::of::<ErrorT>();
+
+    #[cfg(feature = "serde")]
+    if type_id == TypeId::of::<SerdeProblem>() {
+        return true;
+    }
+
+    type_id == TypeId::of::<ProblemAsError>()
+}
+
+/// The wrapped [Problem] if the error is [ProblemAsError] or
+/// [SerdeProblem](super::super::compatibility::SerdeProblem), otherwise the error itself.
+pub(crate) fn unwrap_problem<ErrorT>(error: ErrorT) -> Result<Problem, ErrorT>
+where
+    ErrorT: 'static,
+{
+    let mut error = Some(error);
+    let any: &mut dyn Any = &mut error;
+
+    if let Some(Some(wrapper)) = any
+        .downcast_mut::<Option<ProblemAsError>>()
+        .map(Option::take)
+    {
+        return Ok(wrapper.problem);
+    }
+
+    #[cfg(feature = "serde")]
+    if let Some(Some(wrapper)) = any.downcast_mut::<Option<SerdeProblem>>().map(Option::take) {
+        return Ok(wrapper.problem);
+    }
+
+    Err(error.expect("not taken"))
+}
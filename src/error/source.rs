@@ -0,0 +1,28 @@
+use std::{error::*, fmt};
+
+//
+// SourceError
+//
+
+/// Snapshot of an error's [source](Error::source).
+///
+/// Sources are borrowed from their error, so they cannot be moved into a causation chain. This
+/// type captures their [Display](fmt::Display) instead. See
+/// [via_flattening](crate::Problem::via_flattening).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceError(pub String);
+
+impl SourceError {
+    /// Constructor.
+    pub fn new(source: &dyn Error) -> Self {
+        Self(source.to_string())
+    }
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+impl Error for SourceError {}
@@ -0,0 +1,132 @@
+use super::super::{attachment::*, common::*, problem::*};
+
+use std::{
+    env,
+    fmt::{self, Write},
+    io::{self, IsTerminal},
+};
+
+const RESET: &str = "\x1b[0m";
+const HEADLINE: &str = "\x1b[1;31m";
+const DIM: &str = "\x1b[2m";
+const SUGGESTION: &str = "\x1b[33m";
+
+//
+// ColoredProblem
+//
+
+/// [Display](fmt::Display) for a [Problem] with ANSI colors.
+///
+/// The top cause is red and the rest of the causation chain is dim. Each
+/// [registered](crate::register_attachment) attachment is dim on its own line, as in
+/// [attachment_strings](Problem::attachment_strings). Each [SuggestionAttachment] is yellow on its
+/// own line prefixed with "help:".
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// displayable_attachment!(PortAttachment, u16, "port");
+///
+/// PortAttachment::register();
+///
+/// let problem = "cannot connect"
+///     .into_message_problem()
+///     .with(PortAttachment(8080))
+///     .with_suggestion("check the firewall");
+///
+/// assert_eq!(
+///     ColoredProblem::with_color(&problem, true).to_string(),
+///     "\x1b[1;31mcannot connect\x1b[0m\n\x1b[2mport: 8080\x1b[0m\n\x1b[33mhelp: check the firewall\x1b[0m"
+/// );
+/// ```
+pub struct ColoredProblem<'problem> {
+    /// Problem.
+    pub problem: &'problem Problem,
+
+    /// Whether to emit ANSI colors.
+    pub colorize: bool,
+}
+
+impl<'problem> ColoredProblem<'problem> {
+    /// Constructor.
+    ///
+    /// Colorizes only if stderr is a terminal and the `NO_COLOR` environment variable is not set.
+    pub fn new(problem: &'problem Problem) -> Self {
+        Self::with_color(problem, should_colorize())
+    }
+
+    /// Constructor.
+    pub fn with_color(problem: &'problem Problem, colorize: bool) -> Self {
+        Self { problem, colorize }
+    }
+
+    fn write_colored<DisplayT>(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+        color: &str,
+        display: DisplayT,
+    ) -> fmt::Result
+    where
+        DisplayT: fmt::Display,
+    {
+        if self.colorize {
+            write!(formatter, "{}{}{}", color, display, RESET)
+        } else {
+            write!(formatter, "{}", display)
+        }
+    }
+}
+
+impl fmt::Display for ColoredProblem<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iterator = self.problem.into_iter();
+
+        match iterator.next() {
+            Some(cause) => self.write_colored(formatter, HEADLINE, &cause.error)?,
+            None => self.write_colored(formatter, DIM, self.problem)?,
+        }
+
+        for cause in iterator {
+            self.write_colored(formatter, DIM, format_args!(": {}", cause.error))?;
+        }
+
+        for attachment in self.problem.attachment_strings() {
+            formatter.write_char('\n')?;
+            self.write_colored(formatter, DIM, attachment)?;
+        }
+
+        for suggestion in self.problem.attachments_of_type::<SuggestionAttachment>() {
+            formatter.write_char('\n')?;
+            self.write_colored(
+                formatter,
+                SUGGESTION,
+                format_args!("help: {}", suggestion.0),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+//
+// ColoredProblemDisplay
+//
+
+/// [ColoredProblem] for a [Problem].
+pub trait ColoredProblemDisplay {
+    /// [ColoredProblem].
+    fn colored(&self) -> ColoredProblem<'_>;
+}
+
+impl ColoredProblemDisplay for Problem {
+    fn colored(&self) -> ColoredProblem<'_> {
+        ColoredProblem::new(self)
+    }
+}
+
+// Utils
+
+fn should_colorize() -> bool {
+    env::var_os("NO_COLOR").is_none_or(|no_color| no_color.is_empty()) && io::stderr().is_terminal()
+}
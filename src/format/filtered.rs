@@ -0,0 +1,55 @@
+use super::{
+    super::{cause::*, problem::*},
+    style::*,
+};
+
+use std::fmt;
+
+//
+// FilteredProblem
+//
+
+/// Borrowed view of a [Problem] with only some of its causes.
+///
+/// See [filtered_view](Problem::filtered_view).
+pub struct FilteredProblem<'own> {
+    /// Problem.
+    pub problem: &'own Problem,
+
+    /// Predicate.
+    pub predicate: Box<dyn 'own + Fn(&Cause) -> bool>,
+}
+
+impl FilteredProblem<'_> {
+    /// Iterate the causes that match the predicate.
+    pub fn iter(&self) -> impl Iterator<Item = &Cause> {
+        self.problem
+            .into_iter()
+            .filter(|cause| (self.predicate)(cause))
+    }
+}
+
+impl fmt::Debug for FilteredProblem<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iterator = self.iter().peekable();
+        while let Some(cause) = iterator.next() {
+            write!(formatter, "{:?}", cause.error)?;
+            if iterator.peek().is_some() {
+                writeln!(formatter)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The alternate flag (`{:#}`) will render a multi-line tree, like that of [Problem].
+impl fmt::Display for FilteredProblem<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = if formatter.alternate() {
+            RenderStyle::Tree
+        } else {
+            RenderStyle::Compact
+        };
+        style.write_causes(self.iter(), formatter)
+    }
+}
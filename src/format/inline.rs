@@ -0,0 +1,48 @@
+use super::super::{attachment::*, cause::*, common::*};
+
+use std::fmt;
+
+//
+// InlineCause
+//
+
+/// [Display](fmt::Display) for a [Cause] with its displayable attachments inline.
+///
+/// The attachments are the same as those of the tree style: [SuggestionAttachment],
+/// [RelatedProblem], [SourceSpan], and those registered with [register_attachment].
+pub(crate) struct InlineCause<'own>(pub(crate) &'own Cause);
+
+impl fmt::Display for InlineCause<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.0.error)?;
+
+        let mut first = true;
+        for attachment in &self.0.attachments {
+            let separator = if first { " (" } else { ", " };
+            if let Some(suggestion) = attachment.downcast_ref::<SuggestionAttachment>() {
+                write!(formatter, "{}help: {}", separator, suggestion.0)?;
+            } else if let Some(related) = attachment.downcast_ref::<RelatedProblem>() {
+                write!(formatter, "{}related: {}", separator, related.0)?;
+            } else if let Some(span) = attachment.downcast_ref::<SourceSpan>() {
+                let (line, column) = span.line_and_column();
+                write!(formatter, "{}at: {}:{}", separator, line, column)?;
+            } else if let Some(registered) = registered_attachment(attachment) {
+                write!(
+                    formatter,
+                    "{}{}: {}",
+                    separator,
+                    registered.name,
+                    registered.display(attachment)
+                )?;
+            } else {
+                continue;
+            }
+            first = false;
+        }
+
+        if !first {
+            formatter.write_str(")")?;
+        }
+        Ok(())
+    }
+}
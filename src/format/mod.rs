@@ -0,0 +1,19 @@
+#[cfg(feature = "color")]
+mod colored;
+mod filtered;
+mod inline;
+#[cfg(feature = "backtrace")]
+mod resolved;
+mod style;
+mod tree;
+
+#[cfg(feature = "color")]
+#[allow(unused_imports)]
+pub use colored::*;
+
+#[cfg(feature = "backtrace")]
+#[allow(unused_imports)]
+pub use resolved::*;
+
+#[allow(unused_imports)]
+pub use {filtered::*, inline::*, style::*, tree::*};
@@ -0,0 +1,29 @@
+use std::fmt;
+
+//
+// ResolvedBacktrace
+//
+
+/// Formats a [Backtrace](backtrace::Backtrace) with resolved symbols.
+///
+/// If the backtrace is unresolved then a resolved copy is formatted, leaving the original as is.
+/// To avoid resolving again on every format use
+/// [Problem::resolve_backtraces](crate::Problem::resolve_backtraces) instead.
+pub struct ResolvedBacktrace<'own>(pub &'own backtrace::Backtrace);
+
+impl fmt::Debug for ResolvedBacktrace<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self
+            .0
+            .frames()
+            .iter()
+            .all(|frame| !frame.symbols().is_empty())
+        {
+            fmt::Debug::fmt(self.0, formatter)
+        } else {
+            let mut backtrace = self.0.clone();
+            backtrace.resolve();
+            fmt::Debug::fmt(&backtrace, formatter)
+        }
+    }
+}
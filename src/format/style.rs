@@ -0,0 +1,88 @@
+use super::{
+    super::{cause::*, problem::*},
+    tree::*,
+};
+
+use std::fmt;
+
+//
+// RenderStyle
+//
+
+/// Rendering style for [Problem::write_to].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RenderStyle {
+    /// The causation chain on one line separated by ": ".
+    #[default]
+    Compact,
+
+    /// A multi-line tree, including displayable attachments.
+    Tree,
+}
+
+impl RenderStyle {
+    /// Writes the problem in this style.
+    pub(crate) fn write<WriteT>(self, problem: &Problem, writer: &mut WriteT) -> fmt::Result
+    where
+        WriteT: fmt::Write,
+    {
+        self.write_causes(problem, writer)
+    }
+
+    /// Writes the causes in this style.
+    pub(crate) fn write_causes<'own, IntoIteratorT, WriteT>(
+        self,
+        causes: IntoIteratorT,
+        writer: &mut WriteT,
+    ) -> fmt::Result
+    where
+        IntoIteratorT: IntoIterator<Item = &'own Cause>,
+        WriteT: fmt::Write,
+    {
+        match self {
+            Self::Compact => write_compact(causes, writer, ": "),
+            Self::Tree => write_tree(causes, writer),
+        }
+    }
+}
+
+//
+// SeparatedProblem
+//
+
+/// [Display](fmt::Display) for a [Problem] in the compact style with a custom separator.
+pub(crate) struct SeparatedProblem<'own> {
+    pub(crate) problem: &'own Problem,
+    pub(crate) separator: &'own str,
+}
+
+impl fmt::Display for SeparatedProblem<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.problem.is_empty() {
+            fmt::Display::fmt(self.problem, formatter)
+        } else {
+            write_compact(self.problem, formatter, self.separator)
+        }
+    }
+}
+
+// Utils
+
+fn write_compact<'own, IntoIteratorT, WriteT>(
+    causes: IntoIteratorT,
+    writer: &mut WriteT,
+    separator: &str,
+) -> fmt::Result
+where
+    IntoIteratorT: IntoIterator<Item = &'own Cause>,
+    WriteT: fmt::Write,
+{
+    let mut iterator = causes.into_iter().peekable();
+    while let Some(cause) = iterator.next() {
+        write!(writer, "{}", cause.error)?;
+        if iterator.peek().is_some() {
+            writer.write_str(separator)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use super::super::{attachment::*, cause::*, common::*};
+
+use std::fmt;
+
+/// Writes the causes as a multi-line tree.
+///
+/// Each cause is on its own line, indented by depth, followed by its displayable attachments:
+/// [SuggestionAttachment], [RelatedProblem], [SourceSpan], and those registered with
+/// [register_attachment].
+pub(crate) fn write_tree<'own, IntoIteratorT, WriteT>(
+    causes: IntoIteratorT,
+    writer: &mut WriteT,
+) -> fmt::Result
+where
+    IntoIteratorT: IntoIterator<Item = &'own Cause>,
+    WriteT: fmt::Write,
+{
+    let mut iterator = causes.into_iter().enumerate().peekable();
+    while let Some((depth, cause)) = iterator.next() {
+        if depth != 0 {
+            write!(
+                writer,
+                "{:indentation$}└─ ",
+                "",
+                indentation = 3 * (depth - 1)
+            )?;
+        }
+        write!(writer, "{}", cause.error)?;
+
+        let has_next = iterator.peek().is_some();
+        let branch = if has_next { "│ " } else { "  " };
+        for attachment in &cause.attachments {
+            if let Some(suggestion) = attachment.downcast_ref::<SuggestionAttachment>() {
+                writeln!(writer)?;
+                write!(
+                    writer,
+                    "{:indentation$}{}help: {}",
+                    "",
+                    branch,
+                    suggestion.0,
+                    indentation = 3 * depth
+                )?;
+            } else if let Some(related) = attachment.downcast_ref::<RelatedProblem>() {
+                writeln!(writer)?;
+                write!(
+                    writer,
+                    "{:indentation$}{}related: {}",
+                    "",
+                    branch,
+                    related.0,
+                    indentation = 3 * depth
+                )?;
+            } else if let Some(span) = attachment.downcast_ref::<SourceSpan>() {
+                for line in span.to_string().lines() {
+                    writeln!(writer)?;
+                    write!(
+                        writer,
+                        "{:indentation$}{}{}",
+                        "",
+                        branch,
+                        line,
+                        indentation = 3 * depth
+                    )?;
+                }
+            } else if let Some(registered) = registered_attachment(attachment) {
+                writeln!(writer)?;
+                write!(
+                    writer,
+                    "{:indentation$}{}{}: {}",
+                    "",
+                    branch,
+                    registered.name,
+                    registered.display(attachment),
+                    indentation = 3 * depth
+                )?;
+            }
+        }
+
+        if has_next {
+            writeln!(writer)?;
+        }
+    }
+    Ok(())
+}
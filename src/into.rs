@@ -1,4 +1,4 @@
-use super::problem::*;
+use super::{cause::*, error::*, problem::*};
 
 use std::error::*;
 
@@ -20,3 +20,35 @@ where
         self.into()
     }
 }
+
+//
+// CapturedErrorIntoProblem
+//
+
+/// [CapturedError] into a [Problem].
+///
+/// Note that [CapturedError] does not implement [Error], thus it cannot use [IntoProblem] or
+/// [From]. This will use it as is without re-boxing.
+pub trait CapturedErrorIntoProblem {
+    /// Into a [Problem].
+    fn into_problem(self) -> Problem;
+}
+
+#[cfg(feature = "backtrace")]
+impl CapturedErrorIntoProblem for CapturedError {
+    fn into_problem(self) -> Problem {
+        Problem {
+            causes: [Cause::new(self)].into(),
+        }
+        .with_backtrace()
+    }
+}
+
+#[cfg(not(feature = "backtrace"))]
+impl CapturedErrorIntoProblem for CapturedError {
+    fn into_problem(self) -> Problem {
+        Problem {
+            causes: [Cause::new(self)].into(),
+        }
+    }
+}
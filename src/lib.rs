@@ -15,20 +15,27 @@ mod attachment;
 mod cause;
 mod compatibility;
 mod error;
+mod format;
 mod into;
 mod problem;
 mod problems;
 mod receiver;
+mod redact;
 mod result;
+mod retry;
+mod summary;
 
 /// Common error and attachment types.
 pub mod common;
 
 #[allow(unused_imports)]
 pub use {
-    attachment::*, cause::*, compatibility::*, error::*, into::*, problem::*, problems::*,
-    receiver::*, result::*,
+    attachment::*, cause::*, compatibility::*, error::*, format::*, into::*, problem::*,
+    problems::*, receiver::*, redact::*, result::*, retry::*, summary::*,
 };
 
 #[cfg(feature = "backtrace")]
 pub use backtrace;
+
+#[cfg(feature = "log")]
+pub use log;
@@ -1,6 +1,11 @@
-use super::{attachment::*, cause::*, error::*};
+#[cfg(feature = "serde")]
+use super::compatibility::*;
 
-use std::{any::*, collections::*, error::*, fmt, io};
+use super::{attachment::*, cause::*, error::*, format::*};
+
+use std::{any::*, collections::*, error::*, fmt, io, mem};
+
+const EMPTY_PROBLEM: &str = "(empty problem)";
 
 //
 // Problem
@@ -10,20 +15,85 @@ use std::{any::*, collections::*, error::*, fmt, io};
 ///
 /// Note that this type does not itself implement [Error](Error) directly, but you can use
 /// [into_error](Problem::into_error).
+///
+/// The type is `#[must_use]`, so discarding a problem is warned about wherever it comes from,
+/// including builder methods such as [via](Problem::via) and
+/// [into_problem](crate::IntoProblem::into_problem). Those methods are thus not marked
+/// themselves.
 #[derive(Default)]
+#[must_use]
 pub struct Problem {
     /// Causes in order of causation from top to root.
     pub causes: VecDeque<Cause>,
 }
 
 impl Problem {
+    /// Into [Err].
+    ///
+    /// Useful at the end of a fluent chain, e.g. `return problem.with_exit_code(2).into_err();`.
+    pub fn into_err<OkT>(self) -> Result<OkT, Self> {
+        Err(self)
+    }
+
+    /// Build a single causation chain from errors, the first being the top.
+    ///
+    /// Returns [None] if there are no errors.
+    ///
+    /// Compare with collecting into [Problems](crate::Problems), which keeps each error as a
+    /// separate problem.
+    #[cfg(feature = "backtrace")]
+    pub fn from_errors<IntoIteratorT, ErrorT>(errors: IntoIteratorT) -> Option<Self>
+    where
+        IntoIteratorT: IntoIterator<Item = ErrorT>,
+        ErrorT: 'static + Error + Send + Sync,
+    {
+        let problem = Self {
+            causes: errors.into_iter().map(Cause::from).collect(),
+        };
+        (!problem.is_empty()).then(|| problem.with_backtrace())
+    }
+
+    /// Build a single causation chain from errors, the first being the top.
+    ///
+    /// Returns [None] if there are no errors.
+    ///
+    /// Compare with collecting into [Problems](crate::Problems), which keeps each error as a
+    /// separate problem.
+    #[cfg(not(feature = "backtrace"))]
+    pub fn from_errors<IntoIteratorT, ErrorT>(errors: IntoIteratorT) -> Option<Self>
+    where
+        IntoIteratorT: IntoIterator<Item = ErrorT>,
+        ErrorT: 'static + Error + Send + Sync,
+    {
+        let problem = Self {
+            causes: errors.into_iter().map(Cause::from).collect(),
+        };
+        (!problem.is_empty()).then_some(problem)
+    }
+
     /// Add support for [Error].
     ///
+    /// An empty problem becomes an error that is displayed as "(empty problem)".
+    ///
     /// Take care to avoid adding it into a [Problem]'s causation chain.
     pub fn into_error(self) -> ProblemAsError {
         self.into()
     }
 
+    /// Into a [CapturedError], via [ProblemAsError].
+    ///
+    /// Take care to avoid adding it into a [Problem]'s causation chain.
+    pub fn into_boxed_error(self) -> CapturedError {
+        Box::new(self.into_error())
+    }
+
+    /// True if the causation chain is empty.
+    ///
+    /// An empty problem is displayed as "(empty problem)".
+    pub fn is_empty(&self) -> bool {
+        self.causes.is_empty()
+    }
+
     /// The top of the causation chain.
     pub fn top(&self) -> Option<&Cause> {
         self.causes.front()
@@ -44,28 +114,417 @@ impl Problem {
         self.causes.back_mut()
     }
 
-    /// Inserts our causation chain under that of the given problem.
+    /// The cause at a depth in the causation chain.
+    pub fn cause_at(&self, depth: usize) -> Option<CauseRef<'_, CapturedError>> {
+        self.causes
+            .get(depth)
+            .map(|cause| cause.to_ref(self, depth))
+    }
+
+    /// The top of the causation chain.
+    pub fn top_ref(&self) -> Option<CauseRef<'_, CapturedError>> {
+        self.cause_at(0)
+    }
+
+    /// The root of the causation chain.
+    pub fn root_ref(&self) -> Option<CauseRef<'_, CapturedError>> {
+        self.causes
+            .len()
+            .checked_sub(1)
+            .and_then(|depth| self.cause_at(depth))
+    }
+
+    /// The [Display](fmt::Display) of the top of the causation chain.
+    pub fn top_message(&self) -> Option<String> {
+        self.top().map(|cause| cause.error.to_string())
+    }
+
+    /// The [Display](fmt::Display) of the root of the causation chain.
+    pub fn root_message(&self) -> Option<String> {
+        self.root().map(|cause| cause.error.to_string())
+    }
+
+    /// Iterate the causes in order of causation from top to root, together with their captured
+    /// error type names.
+    pub fn typed_causes(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, &CapturedError, &Vec<CapturedAttachment>)> {
+        self.into_iter()
+            .map(|cause| (cause.error_type_name, &cause.error, &cause.attachments))
+    }
+
+    /// Number of attachments across all causes.
+    pub fn attachments_len(&self) -> usize {
+        self.into_iter().map(|cause| cause.attachment_count()).sum()
+    }
+
+    /// Attachments across all causes rendered as "name: value" strings.
+    ///
+    /// Only attachments of types registered with [register_attachment] are rendered. The others
+    /// are skipped, because we cannot format them.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// string_attachment!(HostAttachment);
+    ///
+    /// register_attachment::<HostAttachment, _>("host", |host, formatter| {
+    ///     write!(formatter, "{}", host.0)
+    /// });
+    ///
+    /// let problem = "cannot connect"
+    ///     .into_message_problem()
+    ///     .with(HostAttachment::new("example.org"))
+    ///     .with(42);
+    ///
+    /// assert_eq!(problem.attachment_strings(), ["host: example.org"]);
+    /// ```
+    pub fn attachment_strings(&self) -> Vec<String> {
+        self.into_iter()
+            .flat_map(|cause| &cause.attachments)
+            .filter_map(|attachment| {
+                registered_attachment(attachment).map(|registered| {
+                    format!("{}: {}", registered.name, registered.display(attachment))
+                })
+            })
+            .collect()
+    }
+
+    /// Calls the visitor for each cause in order of causation from top to root.
+    ///
+    /// The visitor is given the cause's depth.
+    pub fn walk<VisitT>(&self, mut visit: VisitT)
+    where
+        VisitT: FnMut(usize, &Cause),
+    {
+        for (depth, cause) in self.into_iter().enumerate() {
+            visit(depth, cause);
+        }
+    }
+
+    /// Calls the visitor for each cause in order of causation from top to root.
+    ///
+    /// The visitor is given the cause's depth. Stops on the first [Err].
+    pub fn try_walk<VisitT, ErrorT>(&self, mut visit: VisitT) -> Result<(), ErrorT>
+    where
+        VisitT: FnMut(usize, &Cause) -> Result<(), ErrorT>,
+    {
+        for (depth, cause) in self.into_iter().enumerate() {
+            visit(depth, cause)?;
+        }
+        Ok(())
+    }
+
+    /// Number of errors in the causation chain including those nested in
+    /// [source](Error::source).
+    pub fn total_depth(&self) -> usize {
+        self.into_iter()
+            .map(|cause| error_and_source_depth(cause.error.as_ref()))
+            .sum()
+    }
+
+    /// Removes the top cause if its error is of a type.
+    ///
+    /// Returns the error together with the rest of the causation chain (which could be empty).
+    /// Note that the top cause's attachments are dropped.
+    ///
+    /// Otherwise returns self unchanged.
+    pub fn downcast_top<ErrorT>(mut self) -> Result<(ErrorT, Self), Self>
+    where
+        ErrorT: 'static + Error,
+    {
+        match self.causes.pop_front() {
+            Some(mut cause) => match cause.error.downcast() {
+                Ok(error) => Ok((*error, self)),
+
+                Err(error) => {
+                    cause.error = error;
+                    self.causes.push_front(cause);
+                    Err(self)
+                }
+            },
+
+            None => Err(self),
+        }
+    }
+
+    /// Collapses adjacent causes that have the same error type name and
+    /// [Display](fmt::Display).
+    ///
+    /// The upper cause survives and the attachments of the lower cause are appended to it in
+    /// order. Non-adjacent duplicates are kept.
+    ///
+    /// Causes created with [Cause::new] are never collapsed, because their concrete error types are
+    /// unknown. Likewise, deserialized causes are collapsed only if their serialized type names
+    /// are the same.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// # use std::io;
+    /// let mut problem = "inner"
+    ///     .into_message_problem()
+    ///     .via(MessageError::new("outer"))
+    ///     .via(MessageError::new("outer"));
+    /// problem.normalize();
+    /// assert_eq!(problem.to_string(), "outer: inner");
+    ///
+    /// // Different types with the same message
+    /// let mut problem = Problem::default();
+    /// problem.causes.push_back(Cause::new(Box::new(io::Error::other("outer"))));
+    /// problem.causes.push_back(Cause::new(Box::new(MessageError::new("outer"))));
+    /// problem.normalize();
+    /// assert_eq!(problem.causes.len(), 2);
+    /// ```
+    pub fn normalize(&mut self) {
+        let mut causes = VecDeque::with_capacity(self.causes.len());
+        for cause in mem::take(&mut self.causes) {
+            match causes.back_mut() {
+                Some(upper) if is_same_cause(upper, &cause) => {
+                    upper.attachments.extend(cause.attachments)
+                }
+                _ => causes.push_back(cause),
+            }
+        }
+        self.causes = causes;
+    }
+
+    /// Appends the given problem's causation chain behind ours.
+    ///
+    /// Returns us, with our causes first, followed by the given problem's, so that the given
+    /// problem's top cause becomes the cause of our root. Each cause keeps its own attachments.
+    /// Compare with [above](Problem::above).
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let ours = "cannot save".into_message_problem().with_suggestion("call for help");
+    /// let theirs = "disk on fire".into_message_problem();
+    ///
+    /// let problem = ours.under(theirs);
+    /// assert_eq!(problem.to_string(), "cannot save: disk on fire");
+    /// assert!(problem.top().unwrap().attachment_of_type::<SuggestionAttachment>().is_some());
+    /// assert!(problem.root().unwrap().attachment_of_type::<SuggestionAttachment>().is_none());
+    /// ```
     pub fn under(mut self, mut problem: Problem) -> Self {
         self.causes.append(&mut problem.causes);
-        problem.causes = self.causes;
-        problem
+        self
+    }
+
+    /// Appends the given problem's causation chain behind ours, merging the two causes at the
+    /// join.
+    ///
+    /// The merge function is called with our root cause, which is kept, and the given problem's
+    /// top cause, which is removed. For example, to keep our error while collecting both causes'
+    /// attachments:
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let ours = "cannot save".into_message_problem().with_suggestion("call for help");
+    /// let theirs = "disk full"
+    ///     .into_message_problem()
+    ///     .with(ExitCodeAttachment::failure())
+    ///     .via(MessageError::new("disk on fire"));
+    ///
+    /// let problem = ours.under_merging(theirs, |cause, other| {
+    ///     cause.attachments.extend(other.attachments)
+    /// });
+    ///
+    /// assert_eq!(problem.to_string(), "cannot save: disk full");
+    /// assert!(problem.top().unwrap().attachment_of_type::<SuggestionAttachment>().is_some());
+    /// assert!(problem.root().unwrap().attachment_of_type::<ExitCodeAttachment>().is_some());
+    /// ```
+    pub fn under_merging<MergeT>(mut self, mut problem: Problem, merge: MergeT) -> Self
+    where
+        MergeT: FnOnce(&mut Cause, Cause),
+    {
+        if let Some(root) = self.root_mut()
+            && let Some(top) = problem.causes.pop_front()
+        {
+            merge(root, top);
+        }
+        self.under(problem)
     }
 
-    /// Appends our causation chain above that of the given problem.
+    /// Inserts our causation chain behind that of the given problem.
+    ///
+    /// Returns us, with the given problem's causes first, followed by ours, so that our top cause
+    /// becomes the cause of the given problem's root. Each cause keeps its own attachments.
+    /// Compare with [under](Problem::under).
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let ours = "disk on fire".into_message_problem().with_suggestion("call for help");
+    /// let theirs = "cannot save".into_message_problem();
+    ///
+    /// let problem = ours.above(theirs);
+    /// assert_eq!(problem.to_string(), "cannot save: disk on fire");
+    /// assert!(problem.root().unwrap().attachment_of_type::<SuggestionAttachment>().is_some());
+    /// ```
     pub fn above(mut self, mut problem: Problem) -> Self {
         problem.causes.append(&mut self.causes);
-        problem
+        self.causes = problem.causes;
+        self
+    }
+
+    /// Appends the given lower-level problem's causation chain behind ours, so that it becomes
+    /// the deeper causes of our failure.
+    ///
+    /// Same as [under](Problem::under), but reads better when the given problem is what caused
+    /// ours. Attachments, including backtraces, stay with their causes, so both problems'
+    /// backtraces are kept. To keep only one, call
+    /// [coalesce_backtraces](Problem::coalesce_backtraces) afterwards.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let lower = "disk on fire".into_message_problem();
+    /// let problem = "cannot save".into_message_problem().caused_by(lower);
+    /// assert_eq!(problem.to_string(), "cannot save: disk on fire");
+    /// ```
+    pub fn caused_by(self, lower: Problem) -> Self {
+        self.under(lower)
     }
 
     /// Adds the error to the top of the causation chain.
+    ///
+    /// The error's own [source](Error::source) chain, if it has one, stays nested in it. It is
+    /// thus not part of our causation chain but can still be found by functions that recurse into
+    /// sources, such as [has_type](CausationChain::has_type). Compare with
+    /// [via_flattening](Problem::via_flattening).
+    ///
+    /// A [ProblemAsError] or [SerdeProblem](crate::SerdeProblem) is not added as an opaque cause.
+    /// Instead its inner problem's causes are added to the top, as with
+    /// [caused_by](Problem::caused_by).
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let problem = "root"
+    ///     .into_message_problem()
+    ///     .via("top".into_message_problem().into_error());
+    /// assert_eq!(problem.causes.len(), 2);
+    /// assert_eq!(problem.to_string(), "top: root");
+    /// assert!(!problem.has_type::<ProblemAsError>());
+    /// ```
     pub fn via<ErrorT>(mut self, error: ErrorT) -> Self
     where
         ErrorT: 'static + Error + Send + Sync,
     {
+        match unwrap_problem(error) {
+            Ok(problem) => problem.caused_by(self),
+            Err(error) => {
+                self.causes.push_front(error.into());
+                self
+            }
+        }
+    }
+
+    /// Adds the error to the top of the causation chain, followed by a [SourceError] cause for
+    /// each error in its [source](Error::source) chain.
+    ///
+    /// Sources are borrowed from the error and so are captured as snapshots. The original sources
+    /// remain nested in the error and can still be found by functions that recurse into them.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// # use std::{error::*, fmt, io};
+    /// #[derive(Debug)]
+    /// struct SaveError(io::Error);
+    ///
+    /// impl fmt::Display for SaveError {
+    ///     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(formatter, "save")
+    ///     }
+    /// }
+    ///
+    /// impl Error for SaveError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let problem = "lower".into_message_problem();
+    /// let problem = problem.via(SaveError(io::Error::other("disk on fire")));
+    /// assert_eq!(problem.to_string(), "save: lower");
+    ///
+    /// let problem = "lower".into_message_problem();
+    /// let problem = problem.via_flattening(SaveError(io::Error::other("disk on fire")));
+    /// assert_eq!(problem.to_string(), "save: disk on fire: lower");
+    /// assert!(problem.has_type::<io::Error>());
+    /// ```
+    pub fn via_flattening<ErrorT>(mut self, error: ErrorT) -> Self
+    where
+        ErrorT: 'static + Error + Send + Sync,
+    {
+        let mut source = error.source();
+        let mut depth = 0;
+        while let Some(error) = source {
+            depth += 1;
+            self.causes
+                .insert(depth - 1, SourceError::new(error).into());
+            source = error.source();
+        }
         self.causes.push_front(error.into());
         self
     }
 
+    /// Adds the error to the top of the causation chain.
+    ///
+    /// Same as [via](Problem::via).
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let problem = "root".into_message_problem().prepend_cause(LowLevelError);
+    ///
+    /// assert!(problem.top().unwrap().error.is::<LowLevelError>());
+    /// assert_eq!(problem.root().unwrap().error.to_string(), "root");
+    /// ```
+    pub fn prepend_cause<ErrorT>(self, error: ErrorT) -> Self
+    where
+        ErrorT: 'static + Error + Send + Sync,
+    {
+        self.via(error)
+    }
+
+    /// Adds the error to the root of the causation chain.
+    ///
+    /// [via](Problem::via) is the common direction, but this is useful for attributing a deeper
+    /// underlying cause after the fact.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let problem = "top".into_message_problem().append_cause(LowLevelError);
+    ///
+    /// assert_eq!(problem.top().unwrap().error.to_string(), "top");
+    /// assert!(problem.root().unwrap().error.is::<LowLevelError>());
+    /// ```
+    pub fn append_cause<ErrorT>(mut self, error: ErrorT) -> Self
+    where
+        ErrorT: 'static + Error + Send + Sync,
+    {
+        self.causes.push_back(error.into());
+        self
+    }
+
+    /// Inserts the error into the causation chain at a depth.
+    ///
+    /// Depth 0 is the top. A depth beyond the root is clamped, so that the error becomes the new
+    /// root.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let mut problem = "root".into_message_problem().via(MessageError::new("top"));
+    ///
+    /// problem.insert_cause(1, MessageError::new("middle"));
+    /// problem.insert_cause(0, MessageError::new("new top"));
+    /// problem.insert_cause(100, MessageError::new("new root"));
+    ///
+    /// assert_eq!(problem.to_string(), "new top: top: middle: root: new root");
+    /// ```
+    pub fn insert_cause<ErrorT>(&mut self, depth: usize, error: ErrorT)
+    where
+        ErrorT: 'static + Error + Send + Sync,
+    {
+        self.causes
+            .insert(depth.min(self.causes.len()), error.into());
+    }
+
     /// Attach to the top cause.
     pub fn with<AttachmentT>(mut self, attachment: AttachmentT) -> Self
     where
@@ -77,6 +536,20 @@ impl Problem {
         self
     }
 
+    /// Attach all to the top cause.
+    pub fn attach_all<AttachmentT, IntoIteratorT>(mut self, attachments: IntoIteratorT) -> Self
+    where
+        AttachmentT: Any + Send + Sync,
+        IntoIteratorT: IntoIterator<Item = AttachmentT>,
+    {
+        if let Some(cause) = self.top_mut() {
+            for attachment in attachments {
+                cause.attach(attachment);
+            }
+        }
+        self
+    }
+
     /// Attach to the top cause if [Some].
     pub fn maybe_with<AttachmentT>(mut self, attachment: Option<AttachmentT>) -> Self
     where
@@ -90,7 +563,236 @@ impl Problem {
         self
     }
 
+    /// Attach to the top cause if the condition is true.
+    ///
+    /// The attachment is only constructed if needed.
+    pub fn with_if<AttachmentT, FromT>(mut self, condition: bool, from: FromT) -> Self
+    where
+        AttachmentT: Any + Send + Sync,
+        FromT: FnOnce() -> AttachmentT,
+    {
+        if condition && let Some(cause) = self.top_mut() {
+            cause.attach(from());
+        }
+        self
+    }
+
+    /// Clone while sharing the attachments.
+    ///
+    /// Because errors are not cloneable, the function is called to provide a replacement for each
+    /// cause's error. Note that the captured error type name is kept.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let problem = "hello".into_message_problem().with_suggestion("try again");
+    /// let clone = problem.clone_sharing_attachments(|cause| {
+    ///     MessageError::new(&cause.error).into()
+    /// });
+    /// assert_eq!(clone.to_string(), problem.to_string());
+    /// ```
+    #[cfg(feature = "arc-attachments")]
+    pub fn clone_sharing_attachments<CloneErrorT>(&self, mut clone_error: CloneErrorT) -> Self
+    where
+        CloneErrorT: FnMut(&Cause) -> CapturedError,
+    {
+        Self {
+            causes: self
+                .into_iter()
+                .map(|cause| cause.clone_with_error(clone_error(cause)))
+                .collect(),
+        }
+    }
+
+    /// The first backtrace in any cause, for formatting with resolved symbols.
+    ///
+    /// Captured backtraces are unresolved, so formatting them directly shows only addresses.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// fn load_settings() -> Problem {
+    ///     "cannot load".into_message_problem()
+    /// }
+    ///
+    /// let problem = load_settings();
+    /// let backtrace = format!("{:?}", problem.backtrace().unwrap());
+    /// assert!(backtrace.contains("load_settings"));
+    /// ```
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> Option<ResolvedBacktrace<'_>> {
+        self.into_iter().find_map(Cause::backtrace)
+    }
+
+    /// Resolve the symbols of all backtraces.
+    #[cfg(feature = "backtrace")]
+    pub fn resolve_backtraces(&mut self) {
+        for cause in &mut self.causes {
+            cause.resolve_backtrace();
+        }
+    }
+
+    /// Remove the first attachment of a type in any cause and return it.
+    ///
+    /// With the `arc-attachments` feature, attachments that are shared with other problems are
+    /// skipped.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let mut problem = "bad port"
+    ///     .into_message_problem()
+    ///     .with_span("port = 0", 7, 8)
+    ///     .via(MessageError::new("invalid config"));
+    ///
+    /// let span: SourceSpan = problem.take_attachment_of_type().unwrap();
+    /// assert_eq!(span.line_and_column(), (1, 8));
+    /// assert!(problem.attachment_of_type::<SourceSpan>().is_none());
+    /// ```
+    #[cfg(not(feature = "arc-attachments"))]
+    pub fn take_attachment_of_type<AttachmentT>(&mut self) -> Option<AttachmentT>
+    where
+        AttachmentT: 'static,
+    {
+        self.causes
+            .iter_mut()
+            .find_map(|cause| cause.take_attachment_of_type())
+    }
+
+    /// Remove the first attachment of a type in any cause and return it.
+    ///
+    /// With the `arc-attachments` feature, attachments that are shared with other problems are
+    /// skipped.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let mut problem = "bad port"
+    ///     .into_message_problem()
+    ///     .with_span("port = 0", 7, 8)
+    ///     .via(MessageError::new("invalid config"));
+    ///
+    /// let span: SourceSpan = problem.take_attachment_of_type().unwrap();
+    /// assert_eq!(span.line_and_column(), (1, 8));
+    /// assert!(problem.attachment_of_type::<SourceSpan>().is_none());
+    /// ```
+    #[cfg(feature = "arc-attachments")]
+    pub fn take_attachment_of_type<AttachmentT>(&mut self) -> Option<AttachmentT>
+    where
+        AttachmentT: 'static + Send + Sync,
+    {
+        self.causes
+            .iter_mut()
+            .find_map(|cause| cause.take_attachment_of_type())
+    }
+
+    /// Transform all attachments of a type in all causes in place.
+    ///
+    /// With the `arc-attachments` feature, attachments that are shared with other problems are
+    /// skipped.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// attachment!(RetryCount, u32);
+    ///
+    /// let mut problem = "timeout"
+    ///     .into_message_problem()
+    ///     .with(RetryCount(2))
+    ///     .via(MessageError::new("cannot connect"))
+    ///     .with(RetryCount(3));
+    ///
+    /// problem.map_attachments_of_type(|count: &mut RetryCount| count.0 *= 10);
+    ///
+    /// let counts: Vec<_> = problem
+    ///     .attachments_of_type::<RetryCount>()
+    ///     .map(|count| count.0)
+    ///     .collect();
+    /// assert_eq!(counts, [30, 20]);
+    /// ```
+    pub fn map_attachments_of_type<AttachmentT, MapT>(&mut self, mut map: MapT)
+    where
+        AttachmentT: 'static,
+        MapT: FnMut(&mut AttachmentT),
+    {
+        for cause in &mut self.causes {
+            cause.attachments_of_type_mut().for_each(&mut map);
+        }
+    }
+
+    /// Keep only one backtrace.
+    ///
+    /// The root-most wins: we keep the first backtrace of the root-most cause that has one,
+    /// because it is nearest to the origin of the problem. All other backtraces are removed. This
+    /// is useful after merging chains, e.g. with [under](Problem::under), each of which may have
+    /// brought its own backtrace.
+    #[cfg(feature = "backtrace")]
+    pub fn coalesce_backtraces(&mut self) {
+        let keep = self
+            .causes
+            .iter()
+            .rposition(|cause| cause.attachment_of_type::<backtrace::Backtrace>().is_some());
+
+        for (depth, cause) in self.causes.iter_mut().enumerate() {
+            let mut keeping = Some(depth) == keep;
+            cause.attachments.retain(|attachment| {
+                !attachment.is::<backtrace::Backtrace>() || mem::take(&mut keeping)
+            });
+        }
+    }
+
+    /// [Display](fmt::Display) in the compact style with a custom separator between causes.
+    ///
+    /// The plain [Display](fmt::Display) uses ": ".
+    pub fn display_with_separator<'own>(&'own self, separator: &'own str) -> impl fmt::Display {
+        SeparatedProblem {
+            problem: self,
+            separator,
+        }
+    }
+
+    /// A borrowed view with only the causes that match the predicate.
+    ///
+    /// Useful for showing a simplified version of the problem, e.g. to users, while keeping the
+    /// original intact, e.g. for logging.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let problem = "disk on fire"
+    ///     .into_message_problem()
+    ///     .via(LowLevelError)
+    ///     .via(MessageError::new("cannot save"));
+    ///
+    /// let view = problem.filtered_view(|cause| !cause.error.is::<LowLevelError>());
+    /// assert_eq!(view.to_string(), "cannot save: disk on fire");
+    /// assert_eq!(problem.to_string(), "cannot save: low-level: disk on fire");
+    /// ```
+    pub fn filtered_view<'own, PredicateT>(
+        &'own self,
+        predicate: PredicateT,
+    ) -> FilteredProblem<'own>
+    where
+        PredicateT: 'own + Fn(&Cause) -> bool,
+    {
+        FilteredProblem {
+            problem: self,
+            predicate: Box::new(predicate),
+        }
+    }
+
+    /// Writes the problem in a style.
+    ///
+    /// Writes directly to the writer without intermediate allocations (other than those of the
+    /// errors' own [Display](fmt::Display)).
+    pub fn write_to<WriteT>(&self, writer: &mut WriteT, style: RenderStyle) -> fmt::Result
+    where
+        WriteT: fmt::Write,
+    {
+        if self.is_empty() {
+            writer.write_str(EMPTY_PROBLEM)
+        } else {
+            style.write(self, writer)
+        }
+    }
+
     /// Attach a backtrace if we don't already have one.
+    ///
+    /// The backtrace is unresolved. See [attach_backtrace](Cause::attach_backtrace).
     #[cfg(feature = "backtrace")]
     pub fn with_backtrace(mut self) -> Self {
         if self.attachment_of_type::<backtrace::Backtrace>().is_none()
@@ -116,6 +818,10 @@ impl Attachments for Problem {
 
 impl fmt::Debug for Problem {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return formatter.write_str(EMPTY_PROBLEM);
+        }
+
         let mut iterator = self.into_iter().peekable();
         while let Some(cause) = iterator.next() {
             write!(formatter, "{:?}", cause.error)?;
@@ -127,40 +833,63 @@ impl fmt::Debug for Problem {
     }
 }
 
+/// The alternate flag (`{:#}`) will render a multi-line tree, including displayable attachments.
+///
+/// See [write_to](Problem::write_to).
 impl fmt::Display for Problem {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut iterator = self.into_iter().peekable();
-        while let Some(cause) = iterator.next() {
-            write!(formatter, "{}", cause.error)?;
-            if iterator.peek().is_some() {
-                write!(formatter, ": ")?;
-            }
-        }
-        Ok(())
+        let style = if formatter.alternate() {
+            RenderStyle::Tree
+        } else {
+            RenderStyle::Compact
+        };
+        self.write_to(formatter, style)
     }
 }
 
+/// Captures an unresolved backtrace. Without the `backtrace` feature no backtrace is captured.
+///
+/// A [ProblemAsError] or [SerdeProblem](crate::SerdeProblem) is unwrapped to its inner problem
+/// as is.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let problem = Problem::from("inner".into_message_problem().into_error());
+/// assert_eq!(problem.causes.len(), 1);
+/// assert!(problem.has_type::<MessageError>());
+/// assert!(!problem.has_type::<ProblemAsError>());
+/// ```
 #[cfg(feature = "backtrace")]
 impl<ErrorT> From<ErrorT> for Problem
 where
     ErrorT: 'static + Error + Send + Sync,
 {
     fn from(error: ErrorT) -> Self {
-        Self {
-            causes: [error.into()].into(),
+        match unwrap_problem(error) {
+            Ok(problem) => problem,
+            Err(error) => Self {
+                causes: [error.into()].into(),
+            }
+            .with_backtrace(),
         }
-        .with_backtrace()
     }
 }
 
+/// Does not capture a backtrace. With the `backtrace` feature an unresolved backtrace is captured.
+///
+/// A [ProblemAsError] or [SerdeProblem](crate::SerdeProblem) is unwrapped to its inner problem
+/// as is.
 #[cfg(not(feature = "backtrace"))]
 impl<ErrorT> From<ErrorT> for Problem
 where
     ErrorT: 'static + Error + Send + Sync,
 {
     fn from(error: ErrorT) -> Self {
-        Self {
-            causes: [error.into()].into(),
+        match unwrap_problem(error) {
+            Ok(problem) => problem,
+            Err(error) => Self {
+                causes: [error.into()].into(),
+            },
         }
     }
 }
@@ -188,3 +917,36 @@ impl<'own> IntoIterator for &'own Problem {
         self.causes.iter()
     }
 }
+
+// Utils
+
+fn error_and_source_depth(error: &(dyn 'static + Error)) -> usize {
+    // Recursive!
+    1 + error
+        .source()
+        .map(error_and_source_depth)
+        .unwrap_or_default()
+}
+
+fn is_same_cause(cause: &Cause, other: &Cause) -> bool {
+    // Captured errors all have the same type name
+    (cause.error_type_name != type_name::<CapturedError>())
+        && (cause.error_type_name == other.error_type_name)
+        && is_same_serialized_type(cause, other)
+        && (cause.error.to_string() == other.error.to_string())
+}
+
+#[cfg(feature = "serde")]
+fn is_same_serialized_type(cause: &Cause, other: &Cause) -> bool {
+    cause
+        .attachment_of_type::<ErrorTypeNameAttachment>()
+        .map(|type_name| &type_name.0)
+        == other
+            .attachment_of_type::<ErrorTypeNameAttachment>()
+            .map(|type_name| &type_name.0)
+}
+
+#[cfg(not(feature = "serde"))]
+fn is_same_serialized_type(_cause: &Cause, _other: &Cause) -> bool {
+    true
+}
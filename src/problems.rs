@@ -1,6 +1,35 @@
-use super::{error::*, problem::*, receiver::*};
+use super::{error::*, problem::*, receiver::*, summary::*};
 
-use std::{any::*, collections::*, error::Error, fmt, slice, vec};
+use std::{error::Error, fmt, mem, slice, vec};
+
+/// Separates results into the [Ok] values and the [Err] problems, in one pass.
+///
+/// The accumulating analog of collecting into a [Result]\<[Vec], _\>, which fails on the first
+/// [Err].
+///
+/// ```
+/// # use problemo::*;
+/// let (numbers, problems) = partition_problems(["1", "x", "3"].map(str::parse::<u32>));
+/// assert_eq!(numbers, [1, 3]);
+/// assert_eq!(problems.problems.len(), 1);
+/// ```
+pub fn partition_problems<IntoIteratorT, OkT, ErrorT>(
+    results: IntoIteratorT,
+) -> (Vec<OkT>, Problems)
+where
+    IntoIteratorT: IntoIterator<Item = Result<OkT, ErrorT>>,
+    ErrorT: Into<Problem>,
+{
+    let mut oks = Vec::default();
+    let mut problems = Problems::default();
+    for result in results {
+        match result {
+            Ok(ok) => oks.push(ok),
+            Err(error) => problems.add(error),
+        }
+    }
+    (oks, problems)
+}
 
 //
 // Problems
@@ -8,12 +37,13 @@ use std::{any::*, collections::*, error::Error, fmt, slice, vec};
 
 /// Problems.
 #[derive(Default)]
+#[must_use]
 pub struct Problems {
     /// Problems.
     pub problems: Vec<Problem>,
 
-    /// Critical error type IDs.
-    pub critical_error_types: HashSet<TypeId>,
+    /// Critical error types.
+    pub critical_error_types: CriticalErrorTypes,
 }
 
 impl Problems {
@@ -25,25 +55,35 @@ impl Problems {
     /// Marks a top error type as critical.
     pub fn handle_type_as_critical<ErrorT>(&mut self)
     where
-        ErrorT: Any + Error,
+        ErrorT: 'static + Error,
     {
-        self.critical_error_types.insert(TypeId::of::<ErrorT>());
+        self.critical_error_types.add::<ErrorT>();
     }
 
     /// True if the problem's top error is critical.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let mut problems = Problems::default();
+    /// problems.handle_type_as_critical::<ThreadError>();
+    ///
+    /// assert!(problems.is_critical(&"thread panicked".into_thread_problem()));
+    /// assert!(!problems.is_critical(&"too long".into_message_problem()));
+    /// ```
     pub fn is_critical(&self, problem: &Problem) -> bool {
-        problem
-            .top()
-            .map(|cause| self.is_error_critical(&cause.error))
-            .unwrap_or(false)
+        self.critical_error_types.is_critical(problem)
     }
 
     /// True if the error is critical.
     pub fn is_error_critical(&self, error: &CapturedError) -> bool {
-        self.critical_error_types.contains(&error.type_id())
+        self.critical_error_types.is_error_critical(error)
     }
 
     /// Add a problem.
+    ///
+    /// Unlike [give](ProblemReceiver::give) this does not check whether the problem is critical.
+    /// Thus it can be used to absorb a critical problem that [give](ProblemReceiver::give) failed
+    /// on, e.g. when a higher layer decides that it is tolerable after all.
     pub fn add<ProblemT>(&mut self, problem: ProblemT)
     where
         ProblemT: Into<Problem>,
@@ -51,11 +91,174 @@ impl Problems {
         self.problems.push(problem.into())
     }
 
+    /// Add a problem even if it is critical.
+    ///
+    /// Same as [add](Problems::add). Use it where the intent to bypass the critical check should
+    /// be explicit.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let mut problems = Problems::default();
+    /// problems.handle_type_as_critical::<ThreadError>();
+    ///
+    /// if let Err(problem) = problems.give("thread panicked".into_thread_problem()) {
+    ///     // We decided that it is tolerable after all
+    ///     problems.force_add(problem);
+    /// }
+    ///
+    /// assert_eq!(problems.problems.len(), 1);
+    /// ```
+    pub fn force_add(&mut self, problem: Problem) {
+        self.add(problem)
+    }
+
     /// True if there are no problems.
     pub fn is_empty(&self) -> bool {
         self.problems.is_empty()
     }
 
+    /// Removes all problems.
+    ///
+    /// Keeps the critical error types.
+    pub fn clear(&mut self) {
+        self.problems.clear()
+    }
+
+    /// Shrinks the capacity as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.problems.shrink_to_fit()
+    }
+
+    /// Removes and iterates all problems.
+    ///
+    /// Keeps the critical error types, so that we can keep reusing this accumulator.
+    pub fn drain(&mut self) -> vec::Drain<'_, Problem> {
+        self.problems.drain(..)
+    }
+
+    /// Keeps only the problems for which the predicate is true.
+    ///
+    /// Keeps the critical error types.
+    pub fn retain<PredicateT>(&mut self, predicate: PredicateT)
+    where
+        PredicateT: FnMut(&Problem) -> bool,
+    {
+        self.problems.retain(predicate)
+    }
+
+    /// The first problem.
+    pub fn first(&self) -> Option<&Problem> {
+        self.problems.first()
+    }
+
+    /// The last problem.
+    pub fn last(&self) -> Option<&Problem> {
+        self.problems.last()
+    }
+
+    /// The first critical problem.
+    pub fn first_critical(&self) -> Option<&Problem> {
+        self.iter_critical().next()
+    }
+
+    /// Iterate the critical problems.
+    pub fn iter_critical(&self) -> impl Iterator<Item = &Problem> {
+        self.into_iter().filter(|problem| self.is_critical(problem))
+    }
+
+    /// Iterate the non-critical problems.
+    pub fn iter_non_critical(&self) -> impl Iterator<Item = &Problem> {
+        self.into_iter()
+            .filter(|problem| !self.is_critical(problem))
+    }
+
+    /// Iterate the [Display](fmt::Display) of each problem.
+    ///
+    /// Unlike our own [Display](fmt::Display), which joins them with newlines, this lets you
+    /// format each one yourself.
+    pub fn messages(&self) -> impl Iterator<Item = String> {
+        self.into_iter().map(|problem| problem.to_string())
+    }
+
+    /// Separates into critical and non-critical problems, in that order.
+    ///
+    /// Both keep our critical error types.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let mut problems = Problems::default();
+    /// problems.handle_type_as_critical::<ThreadError>();
+    /// problems.add("too long".into_message_problem());
+    /// problems.add("thread panicked".into_thread_problem());
+    /// problems.add("too short".into_message_problem());
+    ///
+    /// let (critical, non_critical) = problems.partition_critical();
+    /// assert_eq!(critical.problems.len(), 1);
+    /// assert!(critical.problems[0].has_type::<ThreadError>());
+    /// assert_eq!(non_critical.problems.len(), 2);
+    /// assert!(non_critical.is_critical(&"again".into_thread_problem()));
+    /// ```
+    pub fn partition_critical(mut self) -> (Self, Self) {
+        let (critical, non_critical) = mem::take(&mut self.problems)
+            .into_iter()
+            .partition(|problem| self.is_critical(problem));
+
+        (
+            Self {
+                problems: critical,
+                critical_error_types: self.critical_error_types.clone(),
+            },
+            Self {
+                problems: non_critical,
+                critical_error_types: self.critical_error_types,
+            },
+        )
+    }
+
+    /// Transform every problem.
+    ///
+    /// Keeps our critical error types.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// let mut problems = Problems::default();
+    /// problems.add("cannot open /srv/secrets.db".into_message_problem());
+    ///
+    /// let problems = problems.map(|problem| problem.into_public("service unavailable", &[]));
+    /// assert_eq!(problems.to_string(), "service unavailable");
+    /// ```
+    pub fn map<MapT>(self, map: MapT) -> Self
+    where
+        MapT: FnMut(Problem) -> Problem,
+    {
+        Self {
+            problems: self.problems.into_iter().map(map).collect(),
+            critical_error_types: self.critical_error_types,
+        }
+    }
+
+    /// Summary.
+    pub fn summary(&self) -> ProblemSummary {
+        self.into()
+    }
+
+    /// Fold into a single [Problem].
+    ///
+    /// If there are no problems the result is an empty problem. If there is one problem it is
+    /// returned as is. Otherwise the result's single cause is a [ProblemsError] to which each
+    /// problem is attached in order, so they can be retrieved with
+    /// [attachments_of_type](crate::Attachments::attachments_of_type).
+    ///
+    /// Compare with [Problem::from], which would instead capture this value as an opaque error.
+    pub fn into_problem(self) -> Problem {
+        let mut problems = self.problems;
+        match problems.len() {
+            0 => Problem::default(),
+            1 => problems.remove(0),
+            count => Problem::from(ProblemsError { count }).attach_all(problems),
+        }
+    }
+
     /// Fails with self if there are problems.
     pub fn check(self) -> Result<(), Self> {
         if self.is_empty() { Ok(()) } else { Err(self) }
@@ -91,11 +294,32 @@ impl fmt::Debug for Problems {
     }
 }
 
+/// The alternate flag (`{:#}`) will render each problem as a multi-line tree.
+///
+/// Problems are separated by newlines, with no trailing newline. Like [Debug](fmt::Debug) it
+/// writes directly to the formatter.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let problems: Problems = [
+///     "b".into_message_problem().via(MessageError::new("a")),
+///     "c".into_message_problem(),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert_eq!(problems.to_string(), "a: b\nc");
+/// assert_eq!(
+///     format!("{:?}", problems),
+///     "MessageError(Some(\"a\"))\nMessageError(Some(\"b\"))\nMessageError(Some(\"c\"))"
+/// );
+/// assert_eq!(Problems::default().to_string(), "");
+/// ```
 impl fmt::Display for Problems {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut iterator = self.into_iter().peekable();
         while let Some(problem) = iterator.next() {
-            write!(formatter, "{}", problem)?;
+            fmt::Display::fmt(problem, formatter)?;
             if iterator.peek().is_some() {
                 writeln!(formatter)?;
             }
@@ -153,3 +377,24 @@ where
         Self::from_iter(iterator.into_iter().map(Problem::from))
     }
 }
+
+//
+// ProblemsError
+//
+
+/// Multiple problems.
+///
+/// See [Problems::into_problem].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProblemsError {
+    /// Number of problems.
+    pub count: usize,
+}
+
+impl fmt::Display for ProblemsError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} problems", self.count)
+    }
+}
+
+impl Error for ProblemsError {}
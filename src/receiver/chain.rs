@@ -0,0 +1,135 @@
+use super::{super::problem::*, outcome::*, receiver::*};
+
+use std::collections::*;
+
+//
+// ReceiverChain
+//
+
+/// Builder for a [ChainedReceiver].
+///
+/// Stages are applied in the order in which they are added. A stage may pass the problem on to the
+/// next stage or drop it. Problems that pass all stages are given to the final receiver.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let mut problems = Problems::default();
+/// let mut receiver = ReceiverChain::new(&mut problems)
+///     .filter(|problem| !problem.has_type::<ThreadError>())
+///     .dedup()
+///     .build();
+///
+/// receiver.give("hello".into_message_problem()).unwrap();
+/// receiver.give("hello".into_message_problem()).unwrap();
+/// receiver.give("goodbye".into_thread_problem()).unwrap();
+/// drop(receiver);
+///
+/// assert_eq!(problems.problems.len(), 1);
+/// ```
+pub struct ReceiverChain<'own> {
+    receiver: ChainedReceiver<'own>,
+}
+
+impl<'own> ReceiverChain<'own> {
+    /// Constructor.
+    pub fn new(receiver: &'own mut dyn ProblemReceiver) -> Self {
+        Self {
+            receiver: ChainedReceiver {
+                stages: Default::default(),
+                receiver,
+            },
+        }
+    }
+
+    /// Add a stage.
+    ///
+    /// Returning [None] drops the problem.
+    pub fn stage<StageT>(mut self, stage: StageT) -> Self
+    where
+        StageT: 'own + FnMut(Problem) -> Option<Problem>,
+    {
+        self.receiver.stages.push(Box::new(stage));
+        self
+    }
+
+    /// Add a stage that drops problems for which the predicate is false.
+    pub fn filter<PredicateT>(self, mut predicate: PredicateT) -> Self
+    where
+        PredicateT: 'own + FnMut(&Problem) -> bool,
+    {
+        self.stage(move |problem| predicate(&problem).then_some(problem))
+    }
+
+    /// Add a stage that drops problems with a [Display](std::fmt::Display) we have already seen.
+    pub fn dedup(self) -> Self {
+        let mut seen = HashSet::new();
+        self.stage(move |problem| seen.insert(problem.to_string()).then_some(problem))
+    }
+
+    /// Add a stage that calls a function for each problem and passes it on.
+    pub fn inspect<InspectT>(self, mut inspect: InspectT) -> Self
+    where
+        InspectT: 'own + FnMut(&Problem),
+    {
+        self.stage(move |problem| {
+            inspect(&problem);
+            Some(problem)
+        })
+    }
+
+    /// Add a stage that logs each problem and passes it on.
+    #[cfg(feature = "log")]
+    pub fn log(self, level: log::Level) -> Self {
+        self.inspect(move |problem| log::log!(level, "{}", problem))
+    }
+
+    /// Build.
+    pub fn build(self) -> ChainedReceiver<'own> {
+        self.receiver
+    }
+}
+
+//
+// ChainedReceiver
+//
+
+/// Chain stage.
+pub type ChainStage<'own> = Box<dyn 'own + FnMut(Problem) -> Option<Problem>>;
+
+/// [ProblemReceiver] built by [ReceiverChain].
+pub struct ChainedReceiver<'own> {
+    /// Stages.
+    pub stages: Vec<ChainStage<'own>>,
+
+    /// Final receiver.
+    pub receiver: &'own mut dyn ProblemReceiver,
+}
+
+impl ProblemReceiver for ChainedReceiver<'_> {
+    fn give(&mut self, problem: Problem) -> Result<(), Problem> {
+        self.give_with_outcome(problem).map(|_| ())
+    }
+
+    fn give_with_outcome(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        self.receiver.inspect(&problem)?;
+        self.give_inspected(problem)
+    }
+
+    fn give_inspected(&mut self, mut problem: Problem) -> Result<GiveOutcome, Problem> {
+        for stage in &mut self.stages {
+            match stage(problem) {
+                Some(next) => problem = next,
+                None => return Ok(GiveOutcome::Dropped),
+            }
+        }
+        self.receiver.give_inspected(problem)
+    }
+
+    fn inspect(&mut self, problem: &Problem) -> Result<(), Problem> {
+        self.receiver.inspect(problem)
+    }
+
+    fn finish(&mut self) -> Result<(), Problem> {
+        self.receiver.finish()
+    }
+}
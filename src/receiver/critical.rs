@@ -0,0 +1,50 @@
+use super::super::{error::*, problem::*};
+
+use std::{any::*, collections::*, error::Error};
+
+//
+// CriticalErrorTypes
+//
+
+/// Critical error types for fail-fast receivers, e.g. [Problems](crate::Problems) and
+/// [RingReceiver](super::RingReceiver).
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let mut critical_error_types = CriticalErrorTypes::default();
+/// critical_error_types.add::<ThreadError>();
+///
+/// assert!(critical_error_types.is_critical(&"thread panicked".into_thread_problem()));
+/// assert!(!critical_error_types.is_critical(&"too long".into_message_problem()));
+/// ```
+#[derive(Clone, Default)]
+pub struct CriticalErrorTypes {
+    /// Maps the type ID to a function that checks whether an error is of that type.
+    ///
+    /// A function is needed because the concrete type cannot be recovered from a
+    /// [CapturedError].
+    pub types: HashMap<TypeId, fn(&CapturedError) -> bool>,
+}
+
+impl CriticalErrorTypes {
+    /// Marks a top error type as critical.
+    pub fn add<ErrorT>(&mut self)
+    where
+        ErrorT: 'static + Error,
+    {
+        self.types
+            .insert(TypeId::of::<ErrorT>(), |error| error.is::<ErrorT>());
+    }
+
+    /// True if the problem's top error is critical.
+    pub fn is_critical(&self, problem: &Problem) -> bool {
+        problem
+            .top()
+            .is_some_and(|cause| self.is_error_critical(&cause.error))
+    }
+
+    /// True if the error is critical.
+    pub fn is_error_critical(&self, error: &CapturedError) -> bool {
+        self.types.values().any(|is_type| is_type(error))
+    }
+}
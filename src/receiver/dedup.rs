@@ -0,0 +1,82 @@
+use super::{super::problem::*, outcome::*, receiver::*};
+
+use std::{collections::*, hash::*};
+
+//
+// DedupByReceiver
+//
+
+/// [ProblemReceiver] that drops problems with a key that it has already seen and forwards the
+/// rest to an inner receiver.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let mut receiver = DedupByReceiver::new(Problems::default(), |problem: &Problem| {
+///     problem.field_name().map(String::from)
+/// });
+///
+/// receiver.give("too long".into_message_problem().with_field_name("name")).unwrap();
+/// receiver.give("too short".into_message_problem().with_field_name("name")).unwrap();
+/// receiver.give("required".into_message_problem().with_field_name("email")).unwrap();
+///
+/// assert_eq!(receiver.inner.problems.len(), 2);
+/// ```
+pub struct DedupByReceiver<ProblemReceiverT, KeyT, KeyFunctionT> {
+    /// Inner receiver.
+    pub inner: ProblemReceiverT,
+
+    /// Key function.
+    pub key: KeyFunctionT,
+
+    /// Keys seen so far.
+    pub seen: HashSet<KeyT>,
+}
+
+impl<ProblemReceiverT, KeyT, KeyFunctionT> DedupByReceiver<ProblemReceiverT, KeyT, KeyFunctionT>
+where
+    ProblemReceiverT: ProblemReceiver,
+    KeyT: Eq + Hash,
+    KeyFunctionT: FnMut(&Problem) -> KeyT,
+{
+    /// Constructor.
+    pub fn new(inner: ProblemReceiverT, key: KeyFunctionT) -> Self {
+        Self {
+            inner,
+            key,
+            seen: Default::default(),
+        }
+    }
+}
+
+impl<ProblemReceiverT, KeyT, KeyFunctionT> ProblemReceiver
+    for DedupByReceiver<ProblemReceiverT, KeyT, KeyFunctionT>
+where
+    ProblemReceiverT: ProblemReceiver,
+    KeyT: Eq + Hash,
+    KeyFunctionT: FnMut(&Problem) -> KeyT,
+{
+    fn give(&mut self, problem: Problem) -> Result<(), Problem> {
+        self.give_with_outcome(problem).map(|_| ())
+    }
+
+    fn give_with_outcome(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        self.inner.inspect(&problem)?;
+        self.give_inspected(problem)
+    }
+
+    fn give_inspected(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        if self.seen.insert((self.key)(&problem)) {
+            self.inner.give_inspected(problem)
+        } else {
+            Ok(GiveOutcome::Dropped)
+        }
+    }
+
+    fn inspect(&mut self, problem: &Problem) -> Result<(), Problem> {
+        self.inner.inspect(problem)
+    }
+
+    fn finish(&mut self) -> Result<(), Problem> {
+        self.inner.finish()
+    }
+}
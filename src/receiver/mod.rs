@@ -1,8 +1,19 @@
+mod chain;
+mod critical;
+mod dedup;
 mod fail_fast;
+mod option;
+mod outcome;
 mod receiver;
 mod r#ref;
 mod result;
+mod ring;
+mod routing;
 mod unwrap;
+mod writer;
 
 #[allow(unused_imports)]
-pub use {fail_fast::*, receiver::*, r#ref::*, result::*, unwrap::*};
+pub use {
+    chain::*, critical::*, dedup::*, fail_fast::*, option::*, outcome::*, receiver::*, r#ref::*,
+    result::*, ring::*, routing::*, unwrap::*, writer::*,
+};
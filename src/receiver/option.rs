@@ -0,0 +1,24 @@
+use super::{super::problem::*, outcome::*, receiver::*};
+
+//
+// Option<Problem>
+//
+
+/// [ProblemReceiver] that keeps the first given problem and drops the rest.
+///
+/// It never fails.
+impl ProblemReceiver for Option<Problem> {
+    fn give(&mut self, problem: Problem) -> Result<(), Problem> {
+        self.give_with_outcome(problem).map(|_| ())
+    }
+
+    fn give_with_outcome(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        Ok(match self {
+            Some(_) => GiveOutcome::Dropped,
+            None => {
+                self.replace(problem);
+                GiveOutcome::Stored
+            }
+        })
+    }
+}
@@ -0,0 +1,17 @@
+//
+// GiveOutcome
+//
+
+/// What a [ProblemReceiver](super::ProblemReceiver) did with a given problem.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GiveOutcome {
+    /// The problem was stored (e.g. accumulated).
+    #[default]
+    Stored,
+
+    /// The problem was dropped (e.g. filtered out or deduplicated).
+    Dropped,
+
+    /// The problem was escalated (e.g. transformed and given to another receiver).
+    Escalated,
+}
@@ -1,4 +1,4 @@
-use super::super::problem::*;
+use super::{super::problem::*, outcome::*};
 
 //
 // ProblemReceiver
@@ -9,12 +9,13 @@ use super::super::problem::*;
 /// Example of usage:
 ///
 /// ```
+/// # use problemo::{common::*, *};
 /// fn divide<ProblemReceiverT>(a: f64, b: f64, problems: &mut ProblemReceiverT) -> Result<Option<f64>, Problem>
 /// where
 ///     ProblemReceiverT: ProblemReceiver,
 /// {
 ///     Ok(if b == 0.0 {
-///         problems.give("division by zero".into_problem())?;
+///         problems.give("division by zero".into_message_problem())?;
 ///         None
 ///     } else {
 ///         Some(a / b)
@@ -26,4 +27,80 @@ pub trait ProblemReceiver {
     ///
     /// Implementations may swallow the problem (e.g. to accumulate it) or return it (fail-fast).
     fn give(&mut self, problem: Problem) -> Result<(), Problem>;
+
+    /// Like [give](ProblemReceiver::give) but also tells us what the receiver did with the problem.
+    ///
+    /// The default implementation calls [give](ProblemReceiver::give) and reports
+    /// [Stored](GiveOutcome::Stored) on [Ok]. Composed receivers (filters, deduplicators, etc.)
+    /// should override it.
+    fn give_with_outcome(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        self.give(problem).map(|_| GiveOutcome::Stored)
+    }
+
+    /// Like [give_with_outcome](ProblemReceiver::give_with_outcome) but for a problem that has
+    /// already been given to [inspect](ProblemReceiver::inspect).
+    ///
+    /// Composed receivers override it to forward the problem without inspecting it again, and
+    /// call it on their inner receiver after inspecting. The default calls
+    /// [give_with_outcome](ProblemReceiver::give_with_outcome).
+    fn give_inspected(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        self.give_with_outcome(problem)
+    }
+
+    /// Looks at a problem without taking ownership of it, e.g. for counting or logging.
+    ///
+    /// Composed receivers (filters, deduplicators, etc.) call it on their inner receiver for
+    /// every problem they are given, before deciding whether to give it, so that an inner
+    /// receiver sees even the problems that are dropped. Each problem is inspected exactly once,
+    /// even when composed receivers are nested. The default does nothing.
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// #[derive(Default)]
+    /// struct Tap {
+    ///     seen: usize,
+    ///     problems: Problems,
+    /// }
+    ///
+    /// impl ProblemReceiver for Tap {
+    ///     fn give(&mut self, problem: Problem) -> Result<(), Problem> {
+    ///         self.problems.give(problem)
+    ///     }
+    ///
+    ///     fn inspect(&mut self, _problem: &Problem) -> Result<(), Problem> {
+    ///         self.seen += 1;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut receiver =
+    ///     DedupByReceiver::new(Tap::default(), |problem: &Problem| problem.to_string());
+    /// receiver.give("hello".into_message_problem()).unwrap();
+    /// receiver.give("hello".into_message_problem()).unwrap();
+    ///
+    /// assert_eq!(receiver.inner.seen, 2);
+    /// assert_eq!(receiver.inner.problems.problems.len(), 1);
+    ///
+    /// // Nested
+    /// let mut receiver = DedupByReceiver::new(
+    ///     DedupByReceiver::new(Tap::default(), |problem: &Problem| problem.to_string()),
+    ///     |problem: &Problem| problem.causes.len(),
+    /// );
+    /// receiver.give("hello".into_message_problem()).unwrap();
+    /// receiver.give("hello".into_message_problem()).unwrap();
+    /// receiver.give("goodbye".into_message_problem()).unwrap();
+    ///
+    /// assert_eq!(receiver.inner.inner.seen, 3);
+    /// assert_eq!(receiver.inner.inner.problems.problems.len(), 1);
+    /// ```
+    fn inspect(&mut self, _problem: &Problem) -> Result<(), Problem> {
+        Ok(())
+    }
+
+    /// Called when no more problems will be given.
+    ///
+    /// Buffering receivers should override it to flush. The default does nothing.
+    fn finish(&mut self) -> Result<(), Problem> {
+        Ok(())
+    }
 }
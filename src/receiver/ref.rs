@@ -1,4 +1,4 @@
-use super::{super::problem::*, receiver::*};
+use super::{super::problem::*, outcome::*, receiver::*};
 
 use std::{cell::*, sync::*};
 
@@ -13,6 +13,22 @@ impl<'own> ProblemReceiver for ProblemReceiverRef<'own> {
     fn give(&mut self, problem: Problem) -> Result<(), Problem> {
         self.borrow_mut().give(problem)
     }
+
+    fn give_with_outcome(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        self.borrow_mut().give_with_outcome(problem)
+    }
+
+    fn give_inspected(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        self.borrow_mut().give_inspected(problem)
+    }
+
+    fn inspect(&mut self, problem: &Problem) -> Result<(), Problem> {
+        self.borrow_mut().inspect(problem)
+    }
+
+    fn finish(&mut self) -> Result<(), Problem> {
+        self.borrow_mut().finish()
+    }
 }
 
 //
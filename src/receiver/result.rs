@@ -15,9 +15,28 @@ where
     /// Like [Result::ok] but gives [Err] to a [ProblemReceiver].
     fn give_ok(self, receiver: &mut ProblemReceiverT) -> Result<Option<OkT>, Problem>;
 
+    /// Like [give_ok](ReportReceiverResult::give_ok) but also logs [Err] before giving it.
+    #[cfg(feature = "log")]
+    fn give_ok_log(
+        self,
+        receiver: &mut ProblemReceiverT,
+        level: log::Level,
+    ) -> Result<Option<OkT>, Problem>;
+
     /// Like [Result::unwrap_or] but gives [Err] to a [ProblemReceiver].
     fn give_unwrap_or(self, receiver: &mut ProblemReceiverT, default: OkT) -> Result<OkT, Problem>;
 
+    /// Like [Result::unwrap_or_else] but gives [Err] to a [ProblemReceiver].
+    ///
+    /// The default is only computed if the receiver did not fail.
+    fn give_ok_or<DefaultT>(
+        self,
+        receiver: &mut ProblemReceiverT,
+        default: DefaultT,
+    ) -> Result<OkT, Problem>
+    where
+        DefaultT: FnOnce() -> OkT;
+
     /// Like [Result::unwrap_or_default] but gives [Err] to a [ProblemReceiver].
     fn give_unwrap_or_default(self, receiver: &mut ProblemReceiverT) -> Result<OkT, Problem>
     where
@@ -39,6 +58,22 @@ where
         }
     }
 
+    #[cfg(feature = "log")]
+    fn give_ok_log(
+        self,
+        receiver: &mut ProblemReceiverT,
+        level: log::Level,
+    ) -> Result<Option<OkT>, Problem> {
+        match self.into_problem() {
+            Ok(ok) => Ok(Some(ok)),
+            Err(problem) => {
+                log::log!(level, "{}", problem);
+                receiver.give(problem)?;
+                Ok(None)
+            }
+        }
+    }
+
     fn give_unwrap_or(self, receiver: &mut ProblemReceiverT, default: OkT) -> Result<OkT, Problem> {
         match self.into_problem() {
             Ok(ok) => Ok(ok),
@@ -49,6 +84,23 @@ where
         }
     }
 
+    fn give_ok_or<DefaultT>(
+        self,
+        receiver: &mut ProblemReceiverT,
+        default: DefaultT,
+    ) -> Result<OkT, Problem>
+    where
+        DefaultT: FnOnce() -> OkT,
+    {
+        match self.into_problem() {
+            Ok(ok) => Ok(ok),
+            Err(problem) => {
+                receiver.give(problem)?;
+                Ok(default())
+            }
+        }
+    }
+
     fn give_unwrap_or_default(self, receiver: &mut ProblemReceiverT) -> Result<OkT, Problem>
     where
         OkT: Default,
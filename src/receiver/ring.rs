@@ -0,0 +1,81 @@
+use super::{super::problem::*, critical::*, receiver::*};
+
+use std::{collections::*, error::Error};
+
+//
+// RingReceiver
+//
+
+/// [ProblemReceiver] that keeps only the most recent problems, up to a capacity.
+///
+/// When a given problem would exceed the capacity, the oldest problem is discarded. Like
+/// [Problems](crate::Problems), it fails fast on critical problems.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let mut receiver = RingReceiver::new(3);
+/// for index in 0..5 {
+///     receiver.give(index.into_message_problem()).unwrap();
+/// }
+///
+/// let messages: Vec<_> = receiver.iter().map(|problem| problem.to_string()).collect();
+/// assert_eq!(messages, ["2", "3", "4"]);
+/// ```
+#[derive(Default)]
+pub struct RingReceiver {
+    /// Problems, oldest first.
+    pub problems: VecDeque<Problem>,
+
+    /// Capacity.
+    pub capacity: usize,
+
+    /// Critical error types.
+    pub critical_error_types: CriticalErrorTypes,
+}
+
+impl RingReceiver {
+    /// Constructor.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            problems: VecDeque::with_capacity(capacity),
+            capacity,
+            critical_error_types: Default::default(),
+        }
+    }
+
+    /// Marks a top error type as critical.
+    pub fn handle_type_as_critical<ErrorT>(&mut self)
+    where
+        ErrorT: 'static + Error,
+    {
+        self.critical_error_types.add::<ErrorT>();
+    }
+
+    /// True if the problem's top error is critical.
+    pub fn is_critical(&self, problem: &Problem) -> bool {
+        self.critical_error_types.is_critical(problem)
+    }
+
+    /// Iterate the retained problems, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Problem> {
+        self.problems.iter()
+    }
+}
+
+impl ProblemReceiver for RingReceiver {
+    fn give(&mut self, problem: Problem) -> Result<(), Problem> {
+        if self.is_critical(&problem) {
+            return Err(problem);
+        }
+
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        while self.problems.len() >= self.capacity {
+            self.problems.pop_front();
+        }
+        self.problems.push_back(problem);
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use super::{
+    super::{error::*, problem::*},
+    outcome::*,
+    receiver::*,
+};
+
+use std::error::Error;
+
+//
+// RoutingReceiver
+//
+
+/// [ProblemReceiver] that forwards each problem to a sink according to its top error type.
+///
+/// Routes are checked in the order in which they were added. Problems that match no route are
+/// forwarded to the default sink.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// # use std::io;
+/// let mut io_problems = Problems::default();
+/// let mut other_problems = Problems::default();
+///
+/// let mut receiver =
+///     RoutingReceiver::new(&mut other_problems).route::<io::Error>(&mut io_problems);
+///
+/// receiver.give(io::Error::other("disk on fire").into_problem()).unwrap();
+/// receiver.give(InvalidError::new("port").into_problem()).unwrap();
+/// drop(receiver);
+///
+/// assert_eq!(io_problems.problems.len(), 1);
+/// assert_eq!(other_problems.problems.len(), 1);
+/// ```
+///
+/// Sinks are [inspected](ProblemReceiver::inspect) before they are given the problem:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// #[derive(Default)]
+/// struct Tap {
+///     seen: usize,
+///     problems: Problems,
+/// }
+///
+/// impl ProblemReceiver for Tap {
+///     fn give(&mut self, problem: Problem) -> Result<(), Problem> {
+///         self.problems.give(problem)
+///     }
+///
+///     fn inspect(&mut self, _problem: &Problem) -> Result<(), Problem> {
+///         self.seen += 1;
+///         Ok(())
+///     }
+/// }
+///
+/// let mut tap = Tap::default();
+/// let mut other_problems = Problems::default();
+///
+/// let mut receiver =
+///     RoutingReceiver::new(&mut other_problems).route::<ThreadError>(&mut tap);
+///
+/// receiver.give("thread panicked".into_thread_problem()).unwrap();
+/// receiver.give("too long".into_message_problem()).unwrap();
+/// drop(receiver);
+///
+/// assert_eq!(tap.seen, 1);
+/// assert_eq!(tap.problems.problems.len(), 1);
+/// ```
+pub struct RoutingReceiver<'own> {
+    /// Routes.
+    pub routes: Vec<Route<'own>>,
+
+    /// Default sink.
+    pub default: &'own mut dyn ProblemReceiver,
+}
+
+impl<'own> RoutingReceiver<'own> {
+    /// Constructor.
+    pub fn new(default: &'own mut dyn ProblemReceiver) -> Self {
+        Self {
+            routes: Default::default(),
+            default,
+        }
+    }
+
+    /// Add a route for a top error type.
+    pub fn route<ErrorT>(mut self, sink: &'own mut dyn ProblemReceiver) -> Self
+    where
+        ErrorT: 'static + Error,
+    {
+        self.routes.push(Route {
+            is_type: |error| error.is::<ErrorT>(),
+            sink,
+        });
+        self
+    }
+
+    fn sink(&mut self, problem: &Problem) -> &mut dyn ProblemReceiver {
+        let route = problem.top().and_then(|cause| {
+            self.routes
+                .iter_mut()
+                .find(|route| (route.is_type)(&cause.error))
+        });
+
+        match route {
+            Some(route) => route.sink,
+            None => self.default,
+        }
+    }
+}
+
+impl ProblemReceiver for RoutingReceiver<'_> {
+    fn give(&mut self, problem: Problem) -> Result<(), Problem> {
+        self.give_with_outcome(problem).map(|_| ())
+    }
+
+    fn give_with_outcome(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        let sink = self.sink(&problem);
+        sink.inspect(&problem)?;
+        sink.give_inspected(problem)
+    }
+
+    fn give_inspected(&mut self, problem: Problem) -> Result<GiveOutcome, Problem> {
+        self.sink(&problem).give_inspected(problem)
+    }
+
+    fn inspect(&mut self, problem: &Problem) -> Result<(), Problem> {
+        self.sink(problem).inspect(problem)
+    }
+
+    fn finish(&mut self) -> Result<(), Problem> {
+        for route in &mut self.routes {
+            route.sink.finish()?;
+        }
+        self.default.finish()
+    }
+}
+
+//
+// Route
+//
+
+/// Route for a [RoutingReceiver].
+pub struct Route<'own> {
+    /// Checks whether an error is of the route's type.
+    pub is_type: fn(&CapturedError) -> bool,
+
+    /// Sink.
+    pub sink: &'own mut dyn ProblemReceiver,
+}
@@ -0,0 +1,72 @@
+use super::{
+    super::{into::*, problem::*},
+    receiver::*,
+};
+
+use std::io;
+
+//
+// WriterReceiver
+//
+
+/// [ProblemReceiver] that writes each given problem to an [io::Write].
+///
+/// Fails if writing fails.
+pub struct WriterReceiver<WriteT>
+where
+    WriteT: io::Write,
+{
+    /// Writer.
+    pub writer: WriteT,
+
+    /// Writes a problem.
+    ///
+    /// The default writes the problem's [Display](std::fmt::Display) followed by a newline.
+    pub write: fn(&mut WriteT, &Problem) -> io::Result<()>,
+
+    /// Flush after this many problems.
+    ///
+    /// The default is 1, meaning that we flush after every problem. 0 means that we only flush on
+    /// [finish](ProblemReceiver::finish).
+    pub flush_every: usize,
+
+    /// Number of problems written so far.
+    pub count: usize,
+}
+
+impl<WriteT> WriterReceiver<WriteT>
+where
+    WriteT: io::Write,
+{
+    /// Constructor.
+    pub fn new(writer: WriteT) -> Self {
+        Self {
+            writer,
+            write: |writer, problem| writeln!(writer, "{}", problem),
+            flush_every: 1,
+            count: 0,
+        }
+    }
+
+    fn write(&mut self, problem: &Problem) -> io::Result<()> {
+        (self.write)(&mut self.writer, problem)?;
+        self.count += 1;
+        if (self.flush_every != 0) && self.count.is_multiple_of(self.flush_every) {
+            self.writer.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl<WriteT> ProblemReceiver for WriterReceiver<WriteT>
+where
+    WriteT: io::Write,
+{
+    fn give(&mut self, problem: Problem) -> Result<(), Problem> {
+        self.write(&problem).map_err(|error| error.into_problem())
+    }
+
+    fn finish(&mut self) -> Result<(), Problem> {
+        self.writer.flush().map_err(|error| error.into_problem())
+    }
+}
@@ -0,0 +1,182 @@
+use super::{cause::*, common::*, problem::*};
+
+use std::{any::*, collections::*, error::Error, fmt, sync::*};
+
+const REDACTED: &str = "[REDACTED]";
+
+static REDACTORS: LazyLock<RwLock<HashMap<TypeId, RedactAttachment>>> =
+    LazyLock::new(Default::default);
+
+/// Register an attachment type as [Redact] so that [Problem::redacted] will keep it.
+///
+/// Example:
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// struct TokenAttachment(String);
+///
+/// impl Redact for TokenAttachment {
+///     fn redact(&self) -> Self {
+///         Self(format!("{}...", &self.0[..4]))
+///     }
+/// }
+///
+/// register_redact::<TokenAttachment>();
+///
+/// let problem = "unauthorized"
+///     .into_message_problem()
+///     .with(TokenAttachment("abcd1234".into()))
+///     .with_suggestion("check your token");
+///
+/// let redacted = problem.redacted();
+/// assert_eq!(redacted.attachment_of_type::<TokenAttachment>().unwrap().0, "abcd...");
+/// assert!(redacted.attachment_of_type::<SuggestionAttachment>().is_none());
+/// ```
+pub fn register_redact<AttachmentT>()
+where
+    AttachmentT: 'static + Redact + Send + Sync,
+{
+    REDACTORS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(TypeId::of::<AttachmentT>(), |attachment, cause| {
+            if let Some(attachment) = attachment.downcast_ref::<AttachmentT>() {
+                cause.attach(attachment.redact());
+            }
+        });
+}
+
+//
+// Redact
+//
+
+/// Attachment that can provide a version of itself that is safe for output.
+///
+/// Must be registered with [register_redact].
+pub trait Redact {
+    /// Redacted version.
+    fn redact(&self) -> Self;
+}
+
+//
+// RedactAttachment
+//
+
+/// Type-erased attachment redactor.
+///
+/// Attaches the redacted version of the attachment to the cause.
+pub type RedactAttachment = fn(&dyn Any, &mut Cause);
+
+//
+// RedactedError
+//
+
+/// Error that stands in for an error in a [redacted](Problem::redacted) [Problem].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RedactedError(pub String);
+
+impl fmt::Display for RedactedError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+impl Error for RedactedError {}
+
+//
+// Problem
+//
+
+impl Problem {
+    /// A copy that is safe for output, e.g. for logging or serialization.
+    ///
+    /// Each error is replaced by a [RedactedError] with the same
+    /// [Display](fmt::Display) and captured type name. Attachments of types registered with
+    /// [register_redact] are replaced by their redacted versions. All other attachments are
+    /// dropped, because we cannot know whether they are safe.
+    pub fn redacted(&self) -> Self {
+        self.redacted_strings::<_, &str>([])
+    }
+
+    /// Like [redacted](Problem::redacted) but also replaces each occurrence of the secrets in the
+    /// error messages with "[REDACTED]".
+    pub fn redacted_strings<IntoIteratorT, SecretT>(&self, secrets: IntoIteratorT) -> Self
+    where
+        IntoIteratorT: IntoIterator<Item = SecretT>,
+        SecretT: AsRef<str>,
+    {
+        let secrets: Vec<_> = secrets.into_iter().collect();
+        let redactors = REDACTORS.read().unwrap_or_else(PoisonError::into_inner);
+
+        Self {
+            causes: self
+                .into_iter()
+                .map(|cause| {
+                    let mut message = cause.error.to_string();
+                    for secret in &secrets {
+                        let secret = secret.as_ref();
+                        if !secret.is_empty() {
+                            message = message.replace(secret, REDACTED);
+                        }
+                    }
+
+                    let mut redacted = Cause::new_with_type_name(
+                        Box::new(RedactedError(message)),
+                        cause.error_type_name,
+                    );
+                    for attachment in &cause.attachments {
+                        if let Some(redact) = redactors.get(&(**attachment).type_id()) {
+                            redact(attachment.as_ref(), &mut redacted);
+                        }
+                    }
+                    redacted
+                })
+                .collect(),
+        }
+    }
+
+    /// Replace the causation chain with a single [MessageError] cause for crossing a trust
+    /// boundary, e.g. a public API.
+    ///
+    /// Only attachments of the kept types are retained, gathered from all causes in order.
+    /// Everything else, including backtraces, is dropped.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// # use problemo::{common::*, *};
+    /// # use std::{any::*, io};
+    /// let problem = io::Error::other("cannot open /srv/secrets.db")
+    ///     .into_problem()
+    ///     .with_suggestion("try again later")
+    ///     .with(ExitCodeAttachment(3.into()))
+    ///     .via(LowLevelError);
+    ///
+    /// let keep = [TypeId::of::<SuggestionAttachment>()];
+    /// let public = problem.into_public("service unavailable", &keep);
+    ///
+    /// assert_eq!(public.to_string(), "service unavailable");
+    /// assert!(!public.has_type::<io::Error>());
+    /// assert!(!public.has_type::<LowLevelError>());
+    /// assert_eq!(
+    ///     public.attachment_of_type::<SuggestionAttachment>().unwrap().0,
+    ///     "try again later"
+    /// );
+    /// assert!(public.attachment_of_type::<ExitCodeAttachment>().is_none());
+    /// ```
+    pub fn into_public<ToStringT>(self, headline: ToStringT, keep: &[TypeId]) -> Self
+    where
+        ToStringT: ToString,
+    {
+        let mut cause = Cause::from(MessageError::new(headline));
+        cause.attachments = self
+            .causes
+            .into_iter()
+            .flat_map(|cause| cause.attachments)
+            .filter(|attachment| keep.contains(&(**attachment).type_id()))
+            .collect();
+        Self {
+            causes: [cause].into(),
+        }
+    }
+}
@@ -55,6 +55,15 @@ pub trait ProblemResult<OkT> {
         AttachmentT: Any + Send + Sync,
         FromT: FnOnce() -> AttachmentT;
 
+    /// Attach all to the top cause.
+    fn attach_all<AttachmentT, IntoIteratorT>(
+        self,
+        attachments: IntoIteratorT,
+    ) -> Result<OkT, Problem>
+    where
+        AttachmentT: Any + Send + Sync,
+        IntoIteratorT: IntoIterator<Item = AttachmentT>;
+
     /// Attach to the top cause if [Some].
     fn maybe_with<AttachmentT>(self, attachment: Option<AttachmentT>) -> Result<OkT, Problem>
     where
@@ -66,6 +75,14 @@ pub trait ProblemResult<OkT> {
         AttachmentT: Any + Send + Sync,
         FromT: FnOnce() -> Option<AttachmentT>;
 
+    /// Attach to the top cause if the condition is true.
+    ///
+    /// The attachment is only constructed if needed.
+    fn with_if<AttachmentT, FromT>(self, condition: bool, from: FromT) -> Result<OkT, Problem>
+    where
+        AttachmentT: Any + Send + Sync,
+        FromT: FnOnce() -> AttachmentT;
+
     /// Attach backtrace.
     #[cfg(feature = "backtrace")]
     fn with_backtrace(self) -> Result<OkT, Problem>;
@@ -109,6 +126,18 @@ where
         self.into_problem().map_err(|problem| problem.with(from()))
     }
 
+    fn attach_all<AttachmentT, IntoIteratorT>(
+        self,
+        attachments: IntoIteratorT,
+    ) -> Result<OkT, Problem>
+    where
+        AttachmentT: Any + Send + Sync,
+        IntoIteratorT: IntoIterator<Item = AttachmentT>,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.attach_all(attachments))
+    }
+
     fn maybe_with<AttachmentT>(self, attachment: Option<AttachmentT>) -> Result<OkT, Problem>
     where
         AttachmentT: Any + Send + Sync,
@@ -126,6 +155,15 @@ where
             .map_err(|problem| problem.maybe_with(from()))
     }
 
+    fn with_if<AttachmentT, FromT>(self, condition: bool, from: FromT) -> Result<OkT, Problem>
+    where
+        AttachmentT: Any + Send + Sync,
+        FromT: FnOnce() -> AttachmentT,
+    {
+        self.into_problem()
+            .map_err(|problem| problem.with_if(condition, from))
+    }
+
     #[cfg(feature = "backtrace")]
     fn with_backtrace(self) -> Result<OkT, Problem> {
         self.into_problem()
@@ -0,0 +1,38 @@
+use super::{common::*, problem::*, result::*};
+
+//
+// retry
+//
+
+/// Calls a function until it succeeds or until we run out of attempts.
+///
+/// The function is given the attempt index (starting at 0). It is always called at least once.
+///
+/// On exhaustion returns the last problem with an [AttemptCount] for the number of attempts made.
+///
+/// ```
+/// # use problemo::{common::*, *};
+/// let result = retry(3, |_| Err::<(), _>("timeout".into_message_problem()));
+/// assert_eq!(result.unwrap_err().attempt(), Some(3));
+/// ```
+pub fn retry<ResultT, OkT, AttemptT>(attempts: usize, mut attempt: AttemptT) -> Result<OkT, Problem>
+where
+    ResultT: IntoProblemResult<OkT>,
+    AttemptT: FnMut(usize) -> ResultT,
+{
+    let attempts = attempts.max(1);
+    let mut index = 0;
+    loop {
+        match attempt(index).into_problem() {
+            Ok(ok) => return Ok(ok),
+
+            Err(problem) => {
+                index += 1;
+                if index == attempts {
+                    let count = u32::try_from(attempts).unwrap_or(u32::MAX);
+                    return Err(problem.with_attempt(count));
+                }
+            }
+        }
+    }
+}
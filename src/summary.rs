@@ -0,0 +1,72 @@
+use super::{common::*, problems::*};
+
+use std::{collections::*, fmt};
+
+//
+// ProblemSummary
+//
+
+/// Summary of [Problems].
+///
+/// Its [Display](fmt::Display) is a one-line rollup, e.g. "3 errors, 12 warnings (2 critical)".
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProblemSummary {
+    /// Number of problems.
+    pub total: usize,
+
+    /// Number of critical problems.
+    pub critical: usize,
+
+    /// Number of problems per [Severity].
+    ///
+    /// Severities without problems are not included.
+    pub by_severity: BTreeMap<Severity, usize>,
+}
+
+impl ProblemSummary {
+    /// Number of problems of a [Severity].
+    pub fn count(&self, severity: Severity) -> usize {
+        self.by_severity.get(&severity).copied().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for ProblemSummary {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.total == 0 {
+            return write!(formatter, "no problems");
+        }
+
+        let mut first = true;
+        for (severity, count) in self.by_severity.iter().rev() {
+            if !first {
+                write!(formatter, ", ")?;
+            }
+            first = false;
+
+            write!(formatter, "{} {}", count, severity)?;
+            if *count != 1 {
+                write!(formatter, "s")?;
+            }
+        }
+
+        if self.critical != 0 {
+            write!(formatter, " ({} critical)", self.critical)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&Problems> for ProblemSummary {
+    fn from(problems: &Problems) -> Self {
+        let mut summary = Self::default();
+        for problem in problems {
+            summary.total += 1;
+            if problems.is_critical(problem) {
+                summary.critical += 1;
+            }
+            *summary.by_severity.entry(problem.severity()).or_default() += 1;
+        }
+        summary
+    }
+}